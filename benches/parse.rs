@@ -0,0 +1,24 @@
+use core::str::FromStr;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use target_lexicon::Triple;
+
+fn parse(c: &mut Criterion) {
+    c.bench_function("parse x86_64-unknown-linux-gnu", |b| {
+        b.iter(|| Triple::from_str(black_box("x86_64-unknown-linux-gnu")))
+    });
+    c.bench_function("parse x86_64-linux-android", |b| {
+        b.iter(|| Triple::from_str(black_box("x86_64-linux-android")))
+    });
+    c.bench_function("parse thumbv7em-none-eabihf", |b| {
+        b.iter(|| Triple::from_str(black_box("thumbv7em-none-eabihf")))
+    });
+    c.bench_function("parse x86_64-apple-macosx10.7.0", |b| {
+        b.iter(|| Triple::from_str(black_box("x86_64-apple-macosx10.7.0")))
+    });
+    c.bench_function("parse x86_64-customvendor-linux", |b| {
+        b.iter(|| Triple::from_str(black_box("x86_64-customvendor-linux")))
+    });
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
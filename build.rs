@@ -26,6 +26,7 @@ mod targets {
 // Stub out `ParseError` to minimally support triple.rs and targets.rs.
 mod parse_error {
     #[derive(Debug)]
+    #[allow(clippy::enum_variant_names)]
     pub enum ParseError {
         UnrecognizedArchitecture(String),
         UnrecognizedVendor(String),
@@ -36,14 +37,15 @@ mod parse_error {
     }
 }
 
-use self::targets::Vendor;
-use self::triple::Triple;
+use self::targets::{Architecture, Environment, Vendor};
+use self::triple::{PointerWidth, Triple};
 
 fn main() {
     let out_dir =
         PathBuf::from(env::var("OUT_DIR").expect("The OUT_DIR environment variable must be set"));
     let target = env::var("TARGET").expect("The TARGET environment variable must be set");
-    let triple = Triple::from_str(&target).expect(&format!("Invalid target name: '{}'", target));
+    let triple =
+        Triple::from_str(&target).unwrap_or_else(|_| panic!("Invalid target name: '{}'", target));
     let out = File::create(out_dir.join("host.rs")).expect("error creating host.rs");
     write_host_rs(out, triple).expect("error writing host.rs");
 }
@@ -58,14 +60,18 @@ fn write_host_rs(mut out: File, triple: Triple) -> io::Result<()> {
     writeln!(out, "#[allow(unused_imports)]")?;
     writeln!(out, "use crate::ArmArchitecture::*;")?;
     writeln!(out, "#[allow(unused_imports)]")?;
-    writeln!(out, "use crate::CustomVendor;")?;
+    writeln!(out, "use crate::{{CustomVendor, EspressifVendor}};")?;
+    writeln!(out, "#[allow(unused_imports)]")?;
+    writeln!(out, "use crate::{{MsvcCrt, MsvcToolset}};")?;
+    writeln!(out, "#[allow(unused_imports)]")?;
+    writeln!(out, "use crate::{{RiscvArchitecture, RiscvExtension}};")?;
     writeln!(out)?;
     writeln!(out, "/// The `Triple` of the current host.")?;
     writeln!(out, "pub const HOST: Triple = Triple {{")?;
     writeln!(
         out,
-        "    architecture: Architecture::{:?},",
-        triple.architecture
+        "    architecture: {},",
+        architecture_display(&triple.architecture)
     )?;
     writeln!(out, "    vendor: {},", vendor_display(&triple.vendor))?;
     writeln!(
@@ -75,8 +81,8 @@ fn write_host_rs(mut out: File, triple: Triple) -> io::Result<()> {
     )?;
     writeln!(
         out,
-        "    environment: Environment::{:?},",
-        triple.environment
+        "    environment: {},",
+        environment_display(&triple.environment)
     )?;
     writeln!(
         out,
@@ -89,7 +95,11 @@ fn write_host_rs(mut out: File, triple: Triple) -> io::Result<()> {
     writeln!(out, "impl Architecture {{")?;
     writeln!(out, "    /// Return the architecture for the current host.")?;
     writeln!(out, "    pub const fn host() -> Self {{")?;
-    writeln!(out, "        Architecture::{:?}", triple.architecture)?;
+    writeln!(
+        out,
+        "        {}",
+        architecture_display(&triple.architecture)
+    )?;
     writeln!(out, "    }}")?;
     writeln!(out, "}}")?;
     writeln!(out)?;
@@ -120,7 +130,7 @@ fn write_host_rs(mut out: File, triple: Triple) -> io::Result<()> {
     writeln!(out, "impl Environment {{")?;
     writeln!(out, "    /// Return the environment for the current host.")?;
     writeln!(out, "    pub const fn host() -> Self {{")?;
-    writeln!(out, "        Environment::{:?}", triple.environment)?;
+    writeln!(out, "        {}", environment_display(&triple.environment))?;
     writeln!(out, "    }}")?;
     writeln!(out, "}}")?;
     writeln!(out)?;
@@ -142,8 +152,8 @@ fn write_host_rs(mut out: File, triple: Triple) -> io::Result<()> {
     writeln!(out, "        Self {{")?;
     writeln!(
         out,
-        "            architecture: Architecture::{:?},",
-        triple.architecture
+        "            architecture: {},",
+        architecture_display(&triple.architecture)
     )?;
     writeln!(
         out,
@@ -157,8 +167,8 @@ fn write_host_rs(mut out: File, triple: Triple) -> io::Result<()> {
     )?;
     writeln!(
         out,
-        "            environment: Environment::{:?},",
-        triple.environment
+        "            environment: {},",
+        environment_display(&triple.environment)
     )?;
     writeln!(
         out,
@@ -172,12 +182,51 @@ fn write_host_rs(mut out: File, triple: Triple) -> io::Result<()> {
     Ok(())
 }
 
+fn architecture_display(architecture: &Architecture) -> String {
+    match architecture {
+        // `RiscvArchitecture`'s fields are private, so build it up from its
+        // constructors instead.
+        Architecture::Riscv(riscv) => {
+            let mut s = match riscv.base_width() {
+                PointerWidth::U64 => "RiscvArchitecture::riscv64()".to_owned(),
+                _ => "RiscvArchitecture::riscv32()".to_owned(),
+            };
+            for extension in riscv.extensions() {
+                s.push_str(&format!(".with_extension(RiscvExtension::{:?})", extension));
+            }
+            format!("Architecture::Riscv({})", s)
+        }
+        known => format!("Architecture::{:?}", known),
+    }
+}
+
+fn environment_display(environment: &Environment) -> String {
+    match environment {
+        Environment::MsvcVersioned(_) => format!(
+            "Environment::msvc({}, {})",
+            environment
+                .msvc_toolset()
+                .map_or("None".to_owned(), |toolset| format!(
+                    "Some(MsvcToolset::{:?})",
+                    toolset
+                )),
+            environment
+                .msvc_crt()
+                .map_or("None".to_owned(), |crt| format!("Some(MsvcCrt::{:?})", crt)),
+        ),
+        known => format!("Environment::{:?}", known),
+    }
+}
+
 fn vendor_display(vendor: &Vendor) -> String {
     match vendor {
         Vendor::Custom(custom) => format!(
             "Vendor::Custom(CustomVendor::Static({:?}))",
             custom.as_str()
         ),
+        Vendor::Espressif(espressif) => {
+            format!("Vendor::Espressif(EspressifVendor::{:?})", espressif)
+        }
         known => format!("Vendor::{:?}", known),
     }
 }
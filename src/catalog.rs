@@ -0,0 +1,439 @@
+// This file defines a catalog of the targets rustc ships with, so that tools
+// can list them and validate target names without running rustc.
+
+use crate::parse_error::ParseError;
+use crate::triple::Triple;
+use alloc::borrow::ToOwned;
+
+/// The names printed by `rustc --print target-list` as of Rust 1.95, less
+/// the ones with an architecture, vendor, operating system or environment
+/// this crate doesn't know yet. Each name here parses into the components
+/// rustc gives the target.
+static RUSTC_TARGETS: &[&str] = &[
+    "aarch64-apple-darwin",
+    "aarch64-apple-ios",
+    "aarch64-apple-ios-macabi",
+    "aarch64-apple-ios-sim",
+    "aarch64-apple-tvos",
+    "aarch64-apple-tvos-sim",
+    "aarch64-apple-watchos",
+    "aarch64-apple-watchos-sim",
+    "aarch64-kmc-solid_asp3",
+    "aarch64-linux-android",
+    "aarch64-nintendo-switch-freestanding",
+    "aarch64-pc-windows-gnullvm",
+    "aarch64-pc-windows-msvc",
+    "aarch64-unknown-freebsd",
+    "aarch64-unknown-fuchsia",
+    "aarch64-unknown-hermit",
+    "aarch64-unknown-linux-gnu",
+    "aarch64-unknown-linux-gnu_ilp32",
+    "aarch64-unknown-linux-musl",
+    "aarch64-unknown-managarm-mlibc",
+    "aarch64-unknown-netbsd",
+    "aarch64-unknown-none",
+    "aarch64-unknown-none-softfloat",
+    "aarch64-unknown-nuttx",
+    "aarch64-unknown-openbsd",
+    "aarch64-unknown-redox",
+    "aarch64-unknown-uefi",
+    "aarch64-uwp-windows-msvc",
+    "aarch64-wrs-vxworks",
+    "amdgcn-amd-amdhsa",
+    "arm-linux-androideabi",
+    "arm-unknown-linux-gnueabi",
+    "arm-unknown-linux-gnueabihf",
+    "arm-unknown-linux-musleabi",
+    "arm-unknown-linux-musleabihf",
+    "armeb-unknown-linux-gnueabi",
+    "armebv7r-none-eabi",
+    "armebv7r-none-eabihf",
+    "armv4t-none-eabi",
+    "armv4t-unknown-linux-gnueabi",
+    "armv5te-none-eabi",
+    "armv5te-unknown-linux-gnueabi",
+    "armv5te-unknown-linux-musleabi",
+    "armv6-none-eabi",
+    "armv6-none-eabihf",
+    "armv6-unknown-freebsd",
+    "armv6-unknown-netbsd-eabihf",
+    "armv6k-nintendo-3ds",
+    "armv7-linux-androideabi",
+    "armv7-rtems-eabihf",
+    "armv7-sony-vita-newlibeabihf",
+    "armv7-unknown-freebsd",
+    "armv7-unknown-linux-gnueabi",
+    "armv7-unknown-linux-gnueabihf",
+    "armv7-unknown-linux-musleabi",
+    "armv7-unknown-linux-musleabihf",
+    "armv7-unknown-netbsd-eabihf",
+    "armv7-wrs-vxworks-eabihf",
+    "armv7a-kmc-solid_asp3-eabi",
+    "armv7a-kmc-solid_asp3-eabihf",
+    "armv7a-none-eabi",
+    "armv7a-none-eabihf",
+    "armv7a-nuttx-eabi",
+    "armv7a-nuttx-eabihf",
+    "armv7r-none-eabi",
+    "armv7r-none-eabihf",
+    "armv7s-apple-ios",
+    "armv8r-none-eabihf",
+    "hexagon-unknown-linux-musl",
+    "hexagon-unknown-none-elf",
+    "i386-apple-ios",
+    "i586-unknown-linux-gnu",
+    "i586-unknown-linux-musl",
+    "i586-unknown-netbsd",
+    "i586-unknown-redox",
+    "i686-apple-darwin",
+    "i686-linux-android",
+    "i686-pc-windows-gnu",
+    "i686-pc-windows-gnullvm",
+    "i686-pc-windows-msvc",
+    "i686-unknown-freebsd",
+    "i686-unknown-haiku",
+    "i686-unknown-linux-gnu",
+    "i686-unknown-linux-musl",
+    "i686-unknown-netbsd",
+    "i686-unknown-openbsd",
+    "i686-unknown-uefi",
+    "i686-uwp-windows-gnu",
+    "i686-uwp-windows-msvc",
+    "i686-win7-windows-gnu",
+    "i686-win7-windows-msvc",
+    "i686-wrs-vxworks",
+    "m68k-unknown-linux-gnu",
+    "m68k-unknown-none-elf",
+    "mips-mti-none-elf",
+    "mips-unknown-linux-gnu",
+    "mips-unknown-linux-musl",
+    "mips-unknown-linux-uclibc",
+    "mips64-openwrt-linux-musl",
+    "mips64-unknown-linux-gnuabi64",
+    "mips64-unknown-linux-muslabi64",
+    "mips64el-unknown-linux-gnuabi64",
+    "mips64el-unknown-linux-muslabi64",
+    "mipsel-mti-none-elf",
+    "mipsel-sony-psp",
+    "mipsel-sony-psx",
+    "mipsel-unknown-linux-gnu",
+    "mipsel-unknown-linux-musl",
+    "mipsel-unknown-linux-uclibc",
+    "mipsel-unknown-netbsd",
+    "mipsel-unknown-none",
+    "mipsisa32r6-unknown-linux-gnu",
+    "mipsisa32r6el-unknown-linux-gnu",
+    "mipsisa64r6-unknown-linux-gnuabi64",
+    "mipsisa64r6el-unknown-linux-gnuabi64",
+    "msp430-none-elf",
+    "nvptx64-nvidia-cuda",
+    "powerpc-unknown-freebsd",
+    "powerpc-unknown-linux-gnu",
+    "powerpc-unknown-linux-gnuspe",
+    "powerpc-unknown-linux-musl",
+    "powerpc-unknown-netbsd",
+    "powerpc-unknown-openbsd",
+    "powerpc-wrs-vxworks",
+    "powerpc-wrs-vxworks-spe",
+    "powerpc64-ibm-aix",
+    "powerpc64-unknown-freebsd",
+    "powerpc64-unknown-linux-gnu",
+    "powerpc64-unknown-linux-musl",
+    "powerpc64-unknown-openbsd",
+    "powerpc64-wrs-vxworks",
+    "powerpc64le-unknown-freebsd",
+    "powerpc64le-unknown-linux-gnu",
+    "powerpc64le-unknown-linux-musl",
+    "riscv32-wrs-vxworks",
+    "riscv32e-unknown-none-elf",
+    "riscv32em-unknown-none-elf",
+    "riscv32emc-unknown-none-elf",
+    "riscv32gc-unknown-linux-gnu",
+    "riscv32gc-unknown-linux-musl",
+    "riscv32i-unknown-none-elf",
+    "riscv32im-unknown-none-elf",
+    "riscv32ima-unknown-none-elf",
+    "riscv32imac-esp-espidf",
+    "riscv32imac-unknown-none-elf",
+    "riscv32imac-unknown-nuttx-elf",
+    "riscv32imafc-esp-espidf",
+    "riscv32imafc-unknown-none-elf",
+    "riscv32imafc-unknown-nuttx-elf",
+    "riscv32imc-esp-espidf",
+    "riscv32imc-unknown-none-elf",
+    "riscv32imc-unknown-nuttx-elf",
+    "riscv64-linux-android",
+    "riscv64-wrs-vxworks",
+    "riscv64gc-unknown-freebsd",
+    "riscv64gc-unknown-fuchsia",
+    "riscv64gc-unknown-hermit",
+    "riscv64gc-unknown-linux-gnu",
+    "riscv64gc-unknown-linux-musl",
+    "riscv64gc-unknown-managarm-mlibc",
+    "riscv64gc-unknown-netbsd",
+    "riscv64gc-unknown-none-elf",
+    "riscv64gc-unknown-nuttx-elf",
+    "riscv64gc-unknown-openbsd",
+    "riscv64gc-unknown-redox",
+    "riscv64im-unknown-none-elf",
+    "riscv64imac-unknown-none-elf",
+    "riscv64imac-unknown-nuttx-elf",
+    "s390x-unknown-linux-gnu",
+    "s390x-unknown-linux-musl",
+    "s390x-unknown-none-softfloat",
+    "sparc-unknown-linux-gnu",
+    "sparc-unknown-none-elf",
+    "sparc64-unknown-linux-gnu",
+    "sparc64-unknown-netbsd",
+    "sparc64-unknown-openbsd",
+    "sparcv9-sun-solaris",
+    "thumbv6m-none-eabi",
+    "thumbv6m-nuttx-eabi",
+    "thumbv7a-none-eabi",
+    "thumbv7a-none-eabihf",
+    "thumbv7a-nuttx-eabi",
+    "thumbv7a-nuttx-eabihf",
+    "thumbv7a-pc-windows-msvc",
+    "thumbv7a-uwp-windows-msvc",
+    "thumbv7em-none-eabi",
+    "thumbv7em-none-eabihf",
+    "thumbv7em-nuttx-eabi",
+    "thumbv7em-nuttx-eabihf",
+    "thumbv7m-none-eabi",
+    "thumbv7m-nuttx-eabi",
+    "thumbv7neon-linux-androideabi",
+    "thumbv7neon-unknown-linux-gnueabihf",
+    "thumbv7neon-unknown-linux-musleabihf",
+    "thumbv8m.base-none-eabi",
+    "thumbv8m.base-nuttx-eabi",
+    "thumbv8m.main-none-eabi",
+    "thumbv8m.main-none-eabihf",
+    "thumbv8m.main-nuttx-eabi",
+    "thumbv8m.main-nuttx-eabihf",
+    "wasm32-unknown-emscripten",
+    "wasm32-unknown-unknown",
+    "wasm32-wali-linux-musl",
+    "wasm32-wasip1",
+    "wasm32-wasip2",
+    "wasm32-wasip3",
+    "wasm64-unknown-unknown",
+    "x86_64-apple-darwin",
+    "x86_64-apple-ios",
+    "x86_64-apple-ios-macabi",
+    "x86_64-apple-tvos",
+    "x86_64-apple-watchos-sim",
+    "x86_64-fortanix-unknown-sgx",
+    "x86_64-linux-android",
+    "x86_64-pc-solaris",
+    "x86_64-pc-windows-gnu",
+    "x86_64-pc-windows-gnullvm",
+    "x86_64-pc-windows-msvc",
+    "x86_64-unikraft-linux-musl",
+    "x86_64-unknown-dragonfly",
+    "x86_64-unknown-freebsd",
+    "x86_64-unknown-fuchsia",
+    "x86_64-unknown-haiku",
+    "x86_64-unknown-hermit",
+    "x86_64-unknown-l4re-uclibc",
+    "x86_64-unknown-linux-gnu",
+    "x86_64-unknown-linux-gnux32",
+    "x86_64-unknown-linux-musl",
+    "x86_64-unknown-managarm-mlibc",
+    "x86_64-unknown-netbsd",
+    "x86_64-unknown-none",
+    "x86_64-unknown-openbsd",
+    "x86_64-unknown-redox",
+    "x86_64-unknown-uefi",
+    "x86_64-uwp-windows-gnu",
+    "x86_64-uwp-windows-msvc",
+    "x86_64-win7-windows-gnu",
+    "x86_64-win7-windows-msvc",
+    "x86_64-wrs-vxworks",
+    "xtensa-esp32-espidf",
+    "xtensa-esp32-none-elf",
+    "xtensa-esp32s2-espidf",
+    "xtensa-esp32s2-none-elf",
+    "xtensa-esp32s3-espidf",
+    "xtensa-esp32s3-none-elf",
+];
+
+impl Triple {
+    /// Return the targets rustc ships with, as far as this crate can
+    /// represent them.
+    pub fn rustc_builtin_targets() -> impl Iterator<Item = Triple> {
+        RUSTC_TARGETS
+            .iter()
+            .map(|name| Self::from_rust_target_name(name).unwrap())
+    }
+
+    /// Parse the name of a target rustc ships with, as accepted by
+    /// `rustc --target`.
+    ///
+    /// Unlike `from_str`, this only accepts names in the catalog, so it can
+    /// be used to validate `--target` flags. Names which parse as triples but
+    /// which rustc doesn't know fail with `ParseError::UnrecognizedField`.
+    pub fn from_rust_target_name(name: &str) -> Result<Self, ParseError> {
+        let triple = Self::from_rustup_target(name)?;
+        if RUSTC_TARGETS.contains(&name) {
+            Ok(triple)
+        } else {
+            Err(ParseError::UnrecognizedField(name.to_owned()))
+        }
+    }
+
+    /// Return the name rustc uses for this triple, if rustc ships with it.
+    ///
+    /// As with `to_rustup_target`, details rustc target names don't carry,
+    /// such as macOS versions, are ignored, so this also finds names for
+    /// triples which are spelled differently, finding `x86_64-unknown-fuchsia`
+    /// for `x86_64-fuchsia`, for example.
+    #[allow(clippy::result_unit_err)]
+    pub fn rust_target_name(&self) -> Result<&'static str, ()> {
+        // Every name in the catalog is already spelled as `to_rustup_target`
+        // spells it, so there's no need to parse the names.
+        let name = self.to_rustup_target();
+        RUSTC_TARGETS
+            .iter()
+            .copied()
+            .find(|target| *target == name)
+            .ok_or(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::targets::{
+        Aarch64Architecture, Architecture, ArmArchitecture, BinaryFormat, Environment,
+        EspressifVendor, Mips32Architecture, OperatingSystem, Vendor,
+    };
+    use alloc::vec::Vec;
+    use core::str::FromStr;
+
+    #[test]
+    fn catalog_roundtrips() {
+        let triples: Vec<Triple> = Triple::rustc_builtin_targets().collect();
+        assert_eq!(triples.len(), RUSTC_TARGETS.len());
+        for (triple, name) in triples.iter().zip(RUSTC_TARGETS) {
+            assert_eq!(triple.rust_target_name(), Ok(*name));
+            assert_eq!(
+                Triple::from_rustup_target(name).unwrap().to_rustup_target(),
+                *name
+            );
+        }
+    }
+
+    #[test]
+    fn catalog_components() {
+        let cases = [
+            (
+                "armv7-rtems-eabihf",
+                Architecture::Arm(ArmArchitecture::Armv7),
+                Vendor::Unknown,
+                OperatingSystem::Rtems,
+                Environment::Eabihf,
+                BinaryFormat::Elf,
+            ),
+            (
+                "thumbv7em-nuttx-eabihf",
+                Architecture::Arm(ArmArchitecture::Thumbv7em),
+                Vendor::Unknown,
+                OperatingSystem::Nuttx,
+                Environment::Eabihf,
+                BinaryFormat::Elf,
+            ),
+            (
+                "aarch64-unknown-nuttx",
+                Architecture::Aarch64(Aarch64Architecture::Aarch64),
+                Vendor::Unknown,
+                OperatingSystem::Nuttx,
+                Environment::Unknown,
+                BinaryFormat::Elf,
+            ),
+            (
+                "mipsel-mti-none-elf",
+                Architecture::Mips32(Mips32Architecture::Mipsel),
+                Vendor::Mti,
+                OperatingSystem::None_,
+                Environment::Unknown,
+                BinaryFormat::Elf,
+            ),
+            (
+                "wasm32-wasip2",
+                Architecture::Wasm32,
+                Vendor::Unknown,
+                OperatingSystem::WasiP2,
+                Environment::Unknown,
+                BinaryFormat::Wasm,
+            ),
+            (
+                "wasm32-wasip3",
+                Architecture::Wasm32,
+                Vendor::Unknown,
+                OperatingSystem::WasiP3,
+                Environment::Unknown,
+                BinaryFormat::Wasm,
+            ),
+            (
+                "xtensa-esp32s3-none-elf",
+                Architecture::Xtensa,
+                Vendor::Espressif(EspressifVendor::Esp32s3),
+                OperatingSystem::None_,
+                Environment::Unknown,
+                BinaryFormat::Elf,
+            ),
+        ];
+        for (name, architecture, vendor, operating_system, environment, binary_format) in
+            cases.iter()
+        {
+            let triple = Triple::from_rust_target_name(name).unwrap();
+            assert_eq!(triple.architecture, *architecture, "{}", name);
+            assert_eq!(triple.vendor, *vendor, "{}", name);
+            assert_eq!(triple.operating_system, *operating_system, "{}", name);
+            assert_eq!(triple.environment, *environment, "{}", name);
+            assert_eq!(triple.binary_format, *binary_format, "{}", name);
+        }
+
+        // The only vendor rustc uses which this crate doesn't name is WALI's.
+        for triple in Triple::rustc_builtin_targets() {
+            let name = triple.rust_target_name().unwrap();
+            if let Vendor::Custom(vendor) = &triple.vendor {
+                assert_eq!(vendor.as_str(), "wali", "{}", name);
+            }
+            if triple.operating_system == OperatingSystem::Unknown {
+                assert_eq!(name.split('-').nth(2), Some("unknown"), "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn rust_target_names() {
+        let pairs = [
+            ("riscv64gc-unknown-linux-gnu", "riscv64gc-unknown-linux-gnu"),
+            ("thumbv8m.main-none-eabihf", "thumbv8m.main-none-eabihf"),
+            ("x86_64-fortanix-unknown-sgx", "x86_64-fortanix-unknown-sgx"),
+            ("x86_64-fuchsia", "x86_64-unknown-fuchsia"),
+            ("x86_64-apple-macosx10.7.0", "x86_64-apple-darwin"),
+            ("wasm32-wasi", "wasm32-wasip1"),
+        ];
+        for (triple, name) in pairs.iter() {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.rust_target_name(), Ok(*name));
+            let parsed = Triple::from_rust_target_name(name).unwrap();
+            assert_eq!(parsed.rust_target_name(), Ok(*name));
+        }
+
+        let triple = Triple::from_str("x86_64-apple-linux").unwrap();
+        assert_eq!(triple.rust_target_name(), Err(()));
+        assert_eq!(
+            Triple::from_rust_target_name("x86_64-apple-linux"),
+            Err(ParseError::UnrecognizedField(
+                "x86_64-apple-linux".to_owned()
+            ))
+        );
+        assert_eq!(
+            Triple::from_rust_target_name("x86_64-unknown-nope"),
+            Err(ParseError::UnrecognizedOperatingSystem("nope".to_owned()))
+        );
+    }
+}
@@ -0,0 +1,531 @@
+// This file defines `CfgExpr`, which represents Rust `cfg` predicates, such
+// as are used in `#[cfg]` attributes and Cargo.toml target sections, along
+// with parsing them and evaluating them for a `Triple`.
+
+use crate::targets::{Abi, Architecture, OperatingSystem};
+use crate::triple::{Endianness, Threading, Triple};
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use core::str::FromStr;
+
+/// A `cfg` predicate, such as `all(target_arch = "aarch64", target_os =
+/// "linux")`. Its `Display` form is the predicate itself, so it needs to be
+/// wrapped in `cfg(...)` to form an attribute or a Cargo.toml target key.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CfgExpr {
+    /// A bare name, such as `unix`.
+    Name(String),
+    /// A key and value, such as `target_os = "linux"`.
+    KeyValue(String, String),
+    /// True if all of the predicates are.
+    All(Vec<CfgExpr>),
+    /// True if any of the predicates are.
+    Any(Vec<CfgExpr>),
+    /// True if the predicate isn't.
+    Not(Box<CfgExpr>),
+}
+
+impl CfgExpr {
+    fn key_value(key: &str, value: &str) -> Self {
+        CfgExpr::KeyValue(key.to_owned(), value.to_owned())
+    }
+}
+
+impl fmt::Display for CfgExpr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (name, exprs) = match self {
+            CfgExpr::Name(name) => return f.write_str(name),
+            CfgExpr::KeyValue(key, value) => {
+                write!(f, "{} = \"", key)?;
+                for c in value.chars() {
+                    if c == '"' || c == '\\' {
+                        f.write_str("\\")?;
+                    }
+                    write!(f, "{}", c)?;
+                }
+                return f.write_str("\"");
+            }
+            CfgExpr::Not(expr) => return write!(f, "not({})", expr),
+            CfgExpr::All(exprs) => ("all", exprs),
+            CfgExpr::Any(exprs) => ("any", exprs),
+        };
+        write!(f, "{}(", name)?;
+        for (i, expr) in exprs.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}", expr)?;
+        }
+        f.write_str(")")
+    }
+}
+
+impl FromStr for CfgExpr {
+    type Err = ();
+
+    /// Parse a predicate, optionally wrapped in `cfg(...)`.
+    fn from_str(s: &str) -> Result<Self, ()> {
+        let s = s.trim();
+        let inner = s
+            .strip_prefix("cfg")
+            .map(str::trim_start)
+            .and_then(|rest| rest.strip_prefix('('))
+            .and_then(|rest| rest.strip_suffix(')'));
+        let mut parser = Parser {
+            rest: inner.unwrap_or(s),
+        };
+        let expr = parser.expr()?;
+        if !parser.rest.trim().is_empty() {
+            return Err(());
+        }
+        Ok(expr)
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Parser<'a> {
+    fn eat(&mut self, c: char) -> bool {
+        self.rest = self.rest.trim_start();
+        match self.rest.strip_prefix(c) {
+            Some(rest) => {
+                self.rest = rest;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn ident(&mut self) -> Result<&'a str, ()> {
+        self.rest = self.rest.trim_start();
+        let len = self
+            .rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(self.rest.len());
+        if len == 0 || self.rest.starts_with(|c: char| c.is_ascii_digit()) {
+            return Err(());
+        }
+        let (ident, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Ok(ident)
+    }
+
+    fn string(&mut self) -> Result<String, ()> {
+        if !self.eat('"') {
+            return Err(());
+        }
+        let mut value = String::new();
+        let mut chars = self.rest.char_indices();
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '"' => {
+                    self.rest = &self.rest[i + 1..];
+                    return Ok(value);
+                }
+                '\\' => value.push(chars.next().ok_or(())?.1),
+                c => value.push(c),
+            }
+        }
+        Err(())
+    }
+
+    fn expr(&mut self) -> Result<CfgExpr, ()> {
+        let name = self.ident()?;
+        if self.eat('=') {
+            return Ok(CfgExpr::KeyValue(name.to_owned(), self.string()?));
+        }
+        if !self.eat('(') {
+            return Ok(CfgExpr::Name(name.to_owned()));
+        }
+
+        let mut exprs = Vec::new();
+        while !self.eat(')') {
+            exprs.push(self.expr()?);
+            if !self.eat(',') {
+                if !self.eat(')') {
+                    return Err(());
+                }
+                break;
+            }
+        }
+        match name {
+            "all" => Ok(CfgExpr::All(exprs)),
+            "any" => Ok(CfgExpr::Any(exprs)),
+            "not" if exprs.len() == 1 => Ok(CfgExpr::Not(Box::new(exprs.pop().unwrap()))),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Triple {
+    /// Return the values rustc reports in `target_family` for this triple.
+    pub fn target_families(&self) -> &'static [&'static str] {
+        let is_wasm = matches!(
+            self.architecture,
+            Architecture::Asmjs | Architecture::Wasm32 | Architecture::Wasm64
+        );
+        match self.operating_system {
+            OperatingSystem::Windows => &["windows"],
+            OperatingSystem::Emscripten => &["unix", "wasm"],
+            _ if is_wasm => &["wasm"],
+            OperatingSystem::Aix
+            | OperatingSystem::Darwin
+            | OperatingSystem::Dragonfly
+            | OperatingSystem::Espidf
+            | OperatingSystem::Freebsd
+            | OperatingSystem::Fuchsia
+            | OperatingSystem::Haiku
+            | OperatingSystem::Ios
+            | OperatingSystem::L4re
+            | OperatingSystem::Linux
+            | OperatingSystem::MacOSX { .. }
+            | OperatingSystem::Netbsd
+            | OperatingSystem::Nintendo3ds
+            | OperatingSystem::Nuttx
+            | OperatingSystem::Openbsd
+            | OperatingSystem::Redox
+            | OperatingSystem::Rtems
+            | OperatingSystem::Solaris
+            | OperatingSystem::Tvos
+            | OperatingSystem::Vita
+            | OperatingSystem::VxWorks
+            | OperatingSystem::Watchos => &["unix"],
+            _ => &[],
+        }
+    }
+
+    /// Test whether a `cfg` predicate holds when compiling for this triple,
+    /// or return `None` if that depends on more than the target.
+    ///
+    /// The names `unix` and `windows` and the keys `target_arch`,
+    /// `target_vendor`, `target_os`, `target_env`, `target_abi`,
+    /// `target_family`, `target_pointer_width`, `target_endian` and
+    /// `target_has_atomic` are understood. Anything else, such as `feature`
+    /// or `debug_assertions`, is undecided, as are values this crate can't
+    /// determine for the triple, such as `target_has_atomic = "128"`. An
+    /// undecided predicate makes the whole expression undecided unless the
+    /// others settle it, as a false one does in `all`.
+    pub fn matches_cfg(&self, expr: &CfgExpr) -> Option<bool> {
+        match expr {
+            CfgExpr::Name(name) => match name.as_str() {
+                "unix" | "windows" => Some(self.target_families().contains(&name.as_str())),
+                _ => None,
+            },
+            CfgExpr::KeyValue(key, value) => match key.as_str() {
+                "target_arch" => Some(value == self.target_arch_str()),
+                "target_vendor" => Some(value == self.target_vendor_str()),
+                "target_os" => Some(value == self.target_os_str()),
+                "target_env" => Some(value == self.target_env_str()),
+                "target_abi" => Some(match self.target_abi() {
+                    Some(abi) => Abi::from_str(value) == Ok(abi),
+                    None => value.is_empty(),
+                }),
+                "target_family" => Some(self.target_families().contains(&value.as_str())),
+                "target_pointer_width" => match self.pointer_width() {
+                    Ok(pointer_width) => Some(value.parse() == Ok(pointer_width.bits())),
+                    Err(()) => None,
+                },
+                "target_endian" => match self.endianness() {
+                    Ok(Endianness::Little) => Some(value == "little"),
+                    Ok(Endianness::Big) => Some(value == "big"),
+                    Err(()) => None,
+                },
+                "target_has_atomic" => self.has_atomic(value),
+                _ => None,
+            },
+            CfgExpr::All(exprs) => {
+                let mut result = Some(true);
+                for expr in exprs {
+                    match self.matches_cfg(expr) {
+                        Some(true) => {}
+                        Some(false) => return Some(false),
+                        None => result = None,
+                    }
+                }
+                result
+            }
+            CfgExpr::Any(exprs) => {
+                let mut result = Some(false);
+                for expr in exprs {
+                    match self.matches_cfg(expr) {
+                        Some(true) => return Some(true),
+                        Some(false) => {}
+                        None => result = None,
+                    }
+                }
+                result
+            }
+            CfgExpr::Not(expr) => self.matches_cfg(expr).map(|matches| !matches),
+        }
+    }
+
+    /// Evaluate `target_has_atomic = "<value>"`. Targets without atomic
+    /// compare-and-swap have none, and others have at least those up to the
+    /// pointer width; wider ones vary by CPU, so they're undecided.
+    fn has_atomic(&self, value: &str) -> Option<bool> {
+        let bits = match value {
+            "ptr" => self.pointer_width().ok()?.bits(),
+            "8" | "16" | "32" | "64" | "128" => value.parse().ok()?,
+            _ => return Some(false),
+        };
+        match self.threading().ok()? {
+            Threading::Unsupported => Some(false),
+            _ if bits <= self.pointer_width().ok()?.bits() => Some(true),
+            _ => None,
+        }
+    }
+
+    /// Return a `cfg` predicate which holds when compiling for this triple,
+    /// built from the values rustc reports for it. The vendor is left out
+    /// when it's `unknown`, and the environment and ABI when rustc reports
+    /// none.
+    pub fn cfg_expr(&self) -> CfgExpr {
+        let mut exprs = Vec::new();
+        exprs.push(CfgExpr::key_value("target_arch", self.target_arch_str()));
+        if self.target_vendor_str() != "unknown" {
+            exprs.push(CfgExpr::key_value(
+                "target_vendor",
+                self.target_vendor_str(),
+            ));
+        }
+        exprs.push(CfgExpr::key_value("target_os", self.target_os_str()));
+        if !self.target_env_str().is_empty() {
+            exprs.push(CfgExpr::key_value("target_env", self.target_env_str()));
+        }
+        if let Some(abi) = self.target_abi() {
+            exprs.push(CfgExpr::KeyValue("target_abi".to_owned(), abi.to_string()));
+        }
+        CfgExpr::All(exprs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use alloc::vec;
+
+    #[test]
+    fn triple_cfg_exprs() {
+        let cases = [
+            (
+                "aarch64-unknown-linux-musl",
+                r#"all(target_arch = "aarch64", target_os = "linux", target_env = "musl")"#,
+            ),
+            (
+                "x86_64-pc-windows-msvc",
+                r#"all(target_arch = "x86_64", target_vendor = "pc", target_os = "windows", target_env = "msvc")"#,
+            ),
+            (
+                "thumbv7em-none-eabihf",
+                r#"all(target_arch = "arm", target_os = "none", target_abi = "eabihf")"#,
+            ),
+            (
+                "x86_64-apple-macosx10.7.0",
+                r#"all(target_arch = "x86_64", target_vendor = "apple", target_os = "macos")"#,
+            ),
+            (
+                "wasm32-wasi",
+                r#"all(target_arch = "wasm32", target_os = "wasi", target_env = "p1")"#,
+            ),
+            (
+                "xtensa-esp32s3-espidf",
+                r#"all(target_arch = "xtensa", target_vendor = "espressif", target_os = "espidf", target_env = "newlib")"#,
+            ),
+        ];
+        for (triple, expr) in cases.iter() {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(format!("{}", triple.cfg_expr()), *expr);
+        }
+    }
+
+    #[test]
+    fn display() {
+        let expr = CfgExpr::Any(vec![
+            CfgExpr::Name("unix".to_owned()),
+            CfgExpr::Not(Box::new(CfgExpr::key_value("target_os", "windows"))),
+        ]);
+        assert_eq!(
+            format!("{}", expr),
+            r#"any(unix, not(target_os = "windows"))"#
+        );
+    }
+
+    #[test]
+    fn parse() {
+        let expr = CfgExpr::from_str(
+            r#"cfg(all(target_os = "linux", target_arch = "aarch64", not(target_env = "musl")))"#,
+        )
+        .unwrap();
+        assert_eq!(
+            expr,
+            CfgExpr::All(vec![
+                CfgExpr::key_value("target_os", "linux"),
+                CfgExpr::key_value("target_arch", "aarch64"),
+                CfgExpr::Not(Box::new(CfgExpr::key_value("target_env", "musl"))),
+            ])
+        );
+
+        for s in [
+            "unix",
+            r#"any(windows, target_family = "wasm")"#,
+            "all()",
+            r#"target_os = "a \"quoted\" \\ name""#,
+        ]
+        .iter()
+        {
+            let expr = CfgExpr::from_str(s).unwrap();
+            assert_eq!(format!("{}", expr), *s);
+        }
+        assert_eq!(
+            CfgExpr::from_str(" any( unix , windows , ) "),
+            CfgExpr::from_str("any(unix, windows)")
+        );
+
+        for s in [
+            "",
+            "cfg()",
+            "not(unix, windows)",
+            "other(unix)",
+            "all(unix",
+            r#"target_os = "linux"#,
+            r#"target_os = linux"#,
+            "unix windows",
+        ]
+        .iter()
+        {
+            assert_eq!(CfgExpr::from_str(s), Err(()), "{}", s);
+        }
+    }
+
+    #[test]
+    fn matches_cfg() {
+        let linux = Triple::from_str("aarch64-unknown-linux-gnu").unwrap();
+        let musl = Triple::from_str("aarch64-unknown-linux-musl").unwrap();
+        let expr = CfgExpr::from_str(
+            r#"cfg(all(target_os = "linux", target_arch = "aarch64", not(target_env = "musl")))"#,
+        )
+        .unwrap();
+        assert_eq!(linux.matches_cfg(&expr), Some(true));
+        assert_eq!(musl.matches_cfg(&expr), Some(false));
+
+        let cases = [
+            ("x86_64-pc-windows-msvc", "windows", Some(true)),
+            ("x86_64-pc-windows-msvc", "unix", Some(false)),
+            (
+                "x86_64-apple-darwin",
+                r#"target_family = "unix""#,
+                Some(true),
+            ),
+            ("wasm32-wasi", r#"target_family = "wasm""#, Some(true)),
+            ("wasm32-wasi", "unix", Some(false)),
+            ("wasm32-wasi", r#"target_env = "p1""#, Some(true)),
+            (
+                "x86_64-unknown-linux-gnux32",
+                r#"target_pointer_width = "32""#,
+                Some(true),
+            ),
+            (
+                "powerpc64-unknown-linux-gnu",
+                r#"target_endian = "big""#,
+                Some(true),
+            ),
+            (
+                "thumbv7em-none-eabihf",
+                r#"target_abi = "eabihf""#,
+                Some(true),
+            ),
+            ("thumbv7em-none-eabihf", r#"target_abi = """#, Some(false)),
+            ("x86_64-unknown-linux-gnu", r#"target_abi = """#, Some(true)),
+            ("x86_64-unknown-linux-gnu", r#"feature = "std""#, None),
+            ("x86_64-unknown-linux-gnu", "debug_assertions", None),
+            ("x86_64-unknown-linux-gnu", r#"not(feature = "std")"#, None),
+            (
+                "x86_64-unknown-linux-gnu",
+                r#"all(windows, feature = "std")"#,
+                Some(false),
+            ),
+            (
+                "x86_64-unknown-linux-gnu",
+                r#"any(unix, feature = "std")"#,
+                Some(true),
+            ),
+            (
+                "x86_64-unknown-linux-gnu",
+                r#"not(target_has_atomic = "64")"#,
+                Some(false),
+            ),
+            (
+                "x86_64-unknown-linux-gnu",
+                r#"target_has_atomic = "ptr""#,
+                Some(true),
+            ),
+            (
+                "x86_64-unknown-linux-gnu",
+                r#"target_has_atomic = "128""#,
+                None,
+            ),
+            (
+                "thumbv7em-none-eabihf",
+                r#"target_has_atomic = "32""#,
+                Some(true),
+            ),
+            (
+                "thumbv6m-none-eabi",
+                r#"target_has_atomic = "8""#,
+                Some(false),
+            ),
+            (
+                "xtensa-esp32-espidf",
+                r#"target_vendor = "espressif""#,
+                Some(true),
+            ),
+            (
+                "riscv32imc-esp-espidf",
+                r#"target_env = "newlib""#,
+                Some(true),
+            ),
+        ];
+        for (triple, expr, expected) in cases.iter() {
+            let triple = Triple::from_str(triple).unwrap();
+            let parsed = CfgExpr::from_str(expr).unwrap();
+            assert_eq!(
+                triple.matches_cfg(&parsed),
+                *expected,
+                "{} {}",
+                triple,
+                expr
+            );
+        }
+
+        for (name, env) in [
+            ("wasm32-wasip1", "p1"),
+            ("wasm32-wasip2", "p2"),
+            ("wasm32-wasip3", "p3"),
+        ]
+        .iter()
+        {
+            let triple = Triple::from_rustup_target(name).unwrap();
+            assert_eq!(triple.rust_target_name(), Ok(*name));
+            assert_eq!(
+                format!("{}", triple.cfg_expr()),
+                format!(
+                    r#"all(target_arch = "wasm32", target_os = "wasi", target_env = "{}")"#,
+                    env
+                )
+            );
+        }
+
+        for vector in crate::test_vectors::TEST_VECTORS {
+            let triple = Triple::from_str(vector.triple).unwrap();
+            let expr = CfgExpr::from_str(&format!("cfg({})", triple.cfg_expr())).unwrap();
+            assert_eq!(triple.matches_cfg(&expr), Some(true), "{}", triple);
+        }
+    }
+}
@@ -4,6 +4,84 @@ use crate::{Architecture, BinaryFormat, Environment, OperatingSystem, Triple, Ve
 // about the current host.
 include!(concat!(env!("OUT_DIR"), "/host.rs"));
 
+// The live overrides on this thread, innermost last, each with the id of its
+// guard, and the id to give the next guard.
+#[cfg(feature = "host-override")]
+std::thread_local! {
+    static OVERRIDES: core::cell::RefCell<alloc::vec::Vec<(u64, Triple)>> =
+        const { core::cell::RefCell::new(alloc::vec::Vec::new()) };
+    static NEXT_OVERRIDE_ID: core::cell::Cell<u64> = const { core::cell::Cell::new(0) };
+}
+
+/// The environment variable which, if set, overrides the host triple when
+/// the `host-override` feature is enabled.
+#[cfg(feature = "host-override")]
+pub const HOST_OVERRIDE_VAR: &str = "TARGET_LEXICON_HOST";
+
+#[cfg(feature = "host-override")]
+impl Triple {
+    /// Return the triple that host-dependent code should treat as the
+    /// current host. This is the triple passed to the innermost live
+    /// `override_host` call on the current thread if there is one, or else
+    /// the triple in the `TARGET_LEXICON_HOST` environment variable if that
+    /// is set, or else `Triple::host()`.
+    ///
+    /// `Triple::host()` and the other `host()` constructors are `const` and
+    /// always describe the host the crate was built for; overrides only
+    /// affect this function and `DefaultToHost`, so host-dependent code has
+    /// to call this to be overridable.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `TARGET_LEXICON_HOST` is set to something that isn't a valid
+    /// triple.
+    pub fn effective_host() -> Self {
+        if let Some((_, triple)) = OVERRIDES.with(|overrides| overrides.borrow().last().cloned()) {
+            return triple;
+        }
+        match std::env::var(HOST_OVERRIDE_VAR) {
+            Ok(s) => s
+                .parse()
+                .unwrap_or_else(|err| panic!("invalid triple in {}: {}", HOST_OVERRIDE_VAR, err)),
+            Err(_) => HOST,
+        }
+    }
+}
+
+/// Make `Triple::effective_host` return `triple` on the current thread until
+/// the returned guard is dropped, for testing host-dependent code. Overrides
+/// may be nested, and the most recent live one is in effect; dropping a guard
+/// ends only its own override, whatever order guards are dropped in.
+#[cfg(feature = "host-override")]
+pub fn override_host(triple: Triple) -> HostOverrideGuard {
+    let id = NEXT_OVERRIDE_ID.with(|next| {
+        let id = next.get();
+        next.set(id + 1);
+        id
+    });
+    OVERRIDES.with(|overrides| overrides.borrow_mut().push((id, triple)));
+    HostOverrideGuard {
+        id,
+        _not_send: core::marker::PhantomData,
+    }
+}
+
+/// A guard which ends a host override when dropped; see `override_host`.
+/// Overrides are per-thread, so the guard can't be sent to another thread.
+#[cfg(feature = "host-override")]
+#[must_use = "the host override ends when the guard is dropped"]
+pub struct HostOverrideGuard {
+    id: u64,
+    _not_send: core::marker::PhantomData<*const ()>,
+}
+
+#[cfg(feature = "host-override")]
+impl Drop for HostOverrideGuard {
+    fn drop(&mut self) {
+        OVERRIDES.with(|overrides| overrides.borrow_mut().retain(|(id, _)| *id != self.id));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     #[cfg(target_os = "linux")]
@@ -53,4 +131,48 @@ mod tests {
         use super::*;
         assert_eq!(HOST, Triple::host());
     }
+
+    #[cfg(feature = "host-override")]
+    #[test]
+    fn host_override() {
+        use super::*;
+        use core::str::FromStr;
+
+        let wasm = Triple::from_str("wasm32-wasi").unwrap();
+        let windows = Triple::from_str("x86_64-pc-windows-msvc").unwrap();
+        let before = Triple::effective_host();
+        {
+            let _outer = override_host(wasm.clone());
+            assert_eq!(Triple::effective_host(), wasm);
+            assert_eq!(Triple::host(), HOST);
+            {
+                let _inner = override_host(windows.clone());
+                assert_eq!(Triple::effective_host(), windows);
+                assert_eq!(crate::DefaultToHost::default().0, windows);
+
+                // Overrides are per-thread.
+                let actual = before.clone();
+                std::thread::spawn(move || assert_eq!(Triple::effective_host(), actual))
+                    .join()
+                    .unwrap();
+            }
+            assert_eq!(Triple::effective_host(), wasm);
+        }
+        assert_eq!(Triple::effective_host(), before);
+
+        // Dropping a guard out of order ends only its own override.
+        let linux = Triple::from_str("aarch64-unknown-linux-gnu").unwrap();
+        let outer = override_host(wasm.clone());
+        let inner = override_host(windows.clone());
+        drop(outer);
+        assert_eq!(Triple::effective_host(), windows);
+        let a = override_host(wasm.clone());
+        let b = override_host(linux.clone());
+        drop(inner);
+        assert_eq!(Triple::effective_host(), linux);
+        drop(b);
+        assert_eq!(Triple::effective_host(), wasm);
+        drop(a);
+        assert_eq!(Triple::effective_host(), before);
+    }
 }
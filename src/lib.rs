@@ -2,45 +2,55 @@
 
 #![deny(missing_docs, trivial_numeric_casts, unused_extern_crates)]
 #![warn(unused_import_braces)]
-#![cfg_attr(
-    feature = "cargo-clippy",
-    warn(
-        clippy::float_arithmetic,
-        clippy::mut_mut,
-        clippy::nonminimal_bool,
-        clippy::option_map_unwrap_or,
-        clippy::option_map_unwrap_or_else,
-        clippy::print_stdout,
-        clippy::unicode_not_nfc,
-        clippy::use_self
-    )
-)]
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
 
+mod catalog;
+mod cfg;
 mod data_model;
 mod host;
+mod llvm;
+#[cfg(feature = "object")]
+mod object;
 mod parse_error;
+#[cfg(feature = "serde")]
+mod serde;
 mod targets;
+#[cfg(any(test, feature = "test-vectors"))]
+pub mod test_vectors;
 #[macro_use]
 mod triple;
 
+pub use self::cfg::CfgExpr;
 pub use self::data_model::{CDataModel, Size};
 pub use self::host::HOST;
+#[cfg(feature = "host-override")]
+pub use self::host::{override_host, HostOverrideGuard, HOST_OVERRIDE_VAR};
 pub use self::parse_error::ParseError;
 pub use self::targets::{
-    Aarch64Architecture, Architecture, ArmArchitecture, BinaryFormat, CustomVendor, Environment,
-    OperatingSystem, Vendor,
+    Aarch64Architecture, Abi, ArchFamily, Architecture, ArmArchitecture, BinaryFormat, Bsd,
+    CustomVendor, Environment, EspressifVendor, KernelFamily, MingwCrt, MsvcCrt, MsvcToolset,
+    MsvcVersion, OperatingSystem, RiscvArchitecture, RiscvExtension, VectorWidth, Vendor,
+};
+pub use self::triple::{
+    Bitness, CallingConvention, CodeModel, CrateType, Endianness, ExtendedTriple, PointerWidth,
+    Threading, Triple, TripleComponent, TripleDiff,
 };
-pub use self::triple::{CallingConvention, Endianness, PointerWidth, Triple};
 
 /// A simple wrapper around `Triple` that provides an implementation of
-/// `Default` which defaults to `Triple::host()`.
+/// `Default` which defaults to `Triple::host()`, or with the `host-override`
+/// feature, to `Triple::effective_host()`.
 pub struct DefaultToHost(pub Triple);
 
 impl Default for DefaultToHost {
+    #[cfg(not(feature = "host-override"))]
     fn default() -> Self {
         Self(Triple::host())
     }
+
+    #[cfg(feature = "host-override")]
+    fn default() -> Self {
+        Self(Triple::effective_host())
+    }
 }
@@ -0,0 +1,356 @@
+// This file defines conversions between `Triple` and the triples LLVM
+// uses, along with the data layout strings LLVM expects for them.
+
+use crate::parse_error::ParseError;
+use crate::targets::{
+    default_binary_format, Aarch64Architecture, Architecture, ArmArchitecture, BinaryFormat,
+    Environment, OperatingSystem, Vendor,
+};
+use crate::triple::{Endianness, PointerWidth, Triple};
+use alloc::borrow::ToOwned;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::str::FromStr;
+
+impl Triple {
+    /// Return the triple LLVM uses for this target.
+    ///
+    /// The vendor is always included, spelled as LLVM spells it, so Sony is
+    /// `scei` on the PS4 and `sie` on the PS5, and Espressif is always `esp`.
+    /// Aarch64 on Apple platforms is called
+    /// `arm64`, `darwin` is called `macosx`, and RISC-V architectures are
+    /// reduced to their base ISA, as LLVM takes the extensions as target
+    /// features instead. Environments LLVM doesn't distinguish, such as
+    /// `gnullvm` and versioned MSVC toolsets, are reduced to the one it does.
+    pub fn to_llvm_triple(&self) -> String {
+        let mut llvm = String::new();
+        match (&self.architecture, &self.vendor) {
+            (Architecture::Aarch64(Aarch64Architecture::Aarch64), Vendor::Apple) => {
+                llvm.push_str("arm64")
+            }
+            (Architecture::Arm(ArmArchitecture::Thumbv7neon), _) => llvm.push_str("armv7"),
+            (Architecture::Riscv(riscv), _) => match riscv.base_width() {
+                PointerWidth::U64 => llvm.push_str("riscv64"),
+                _ => llvm.push_str("riscv32"),
+            },
+            (architecture, _) => llvm.push_str(&architecture.to_string()),
+        }
+        llvm.push('-');
+        match self.vendor {
+            Vendor::Espressif(_) => llvm.push_str("esp"),
+            _ => llvm.push_str(self.vendor_str()),
+        }
+        llvm.push('-');
+        match self.operating_system {
+            OperatingSystem::Darwin => llvm.push_str("macosx"),
+            operating_system => llvm.push_str(&operating_system.to_string()),
+        }
+        match self.environment {
+            Environment::Unknown => {}
+            Environment::Gnullvm | Environment::GnuMsvcrt | Environment::GnuUcrt => {
+                llvm.push_str("-gnu")
+            }
+            Environment::MsvcVersioned(_) => llvm.push_str("-msvc"),
+            Environment::Sim => llvm.push_str("-simulator"),
+            environment => {
+                llvm.push('-');
+                llvm.push_str(&environment.to_string());
+            }
+        }
+        if self.binary_format != default_binary_format(self) {
+            llvm.push('-');
+            llvm.push_str(&self.binary_format.to_string());
+        }
+        llvm
+    }
+
+    /// Parse a triple as LLVM writes it.
+    ///
+    /// Operating system versions are accepted, as in `arm64-apple-ios14.0`
+    /// or `x86_64-apple-macosx10.15`; they're kept for macOS, filling in
+    /// missing minor and patch numbers with zeros, and dropped elsewhere, as
+    /// `OperatingSystem` has nowhere to put them. The vendor may be omitted,
+    /// as with any triple.
+    pub fn from_llvm_triple(s: &str) -> Result<Self, ParseError> {
+        let mut parts = s.split('-');
+        let mut normalized: Vec<String> = Vec::new();
+        if let Some(architecture) = parts.next() {
+            normalized.push(architecture.to_owned());
+        }
+        for part in parts {
+            normalized.push(normalize_llvm_part(part));
+        }
+        Self::from_str(&normalized.join("-"))
+    }
+
+    /// Return the LLVM data layout string for this triple, as used by LLVM 20
+    /// and the corresponding rustc targets.
+    ///
+    /// Fails for architectures and binary formats whose layouts aren't
+    /// recorded here, and for ABIs which change the layout of an
+    /// architecture, such as aarch64's ILP32.
+    #[allow(clippy::result_unit_err)]
+    pub fn llvm_data_layout(&self) -> Result<&'static str, ()> {
+        let big_endian = self.endianness() == Ok(Endianness::Big);
+        Ok(match (self.architecture, self.binary_format) {
+            (Architecture::X86_64, BinaryFormat::Elf) if self.environment == Environment::Gnux32 => {
+                "e-m:e-p:32:32-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128"
+            }
+            (Architecture::X86_64, BinaryFormat::Elf) => {
+                "e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128"
+            }
+            (Architecture::X86_64, BinaryFormat::Macho) => {
+                "e-m:o-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128"
+            }
+            (Architecture::X86_64, BinaryFormat::Coff) => {
+                "e-m:w-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128"
+            }
+            (Architecture::X86_32(_), BinaryFormat::Elf) => {
+                "e-m:e-p:32:32-p270:32:32-p271:32:32-p272:64:64-i128:128-f64:32:64-f80:32-n8:16:32-S128"
+            }
+            (Architecture::X86_32(_), BinaryFormat::Macho) => {
+                "e-m:o-p:32:32-p270:32:32-p271:32:32-p272:64:64-i128:128-f64:32:64-f80:128-n8:16:32-S128"
+            }
+            (Architecture::X86_32(_), BinaryFormat::Coff) => {
+                "e-m:x-p:32:32-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:32-n8:16:32-a:0:32-S32"
+            }
+            (Architecture::Aarch64(_), _) if self.environment == Environment::GnuIlp32 => {
+                return Err(())
+            }
+            (Architecture::Aarch64(_), BinaryFormat::Elf) if big_endian => {
+                "E-m:e-p270:32:32-p271:32:32-p272:64:64-i8:8:32-i16:16:32-i64:64-i128:128-n32:64-S128-Fn32"
+            }
+            (Architecture::Aarch64(_), BinaryFormat::Elf) => {
+                "e-m:e-p270:32:32-p271:32:32-p272:64:64-i8:8:32-i16:16:32-i64:64-i128:128-n32:64-S128-Fn32"
+            }
+            (Architecture::Aarch64(Aarch64Architecture::Aarch64), BinaryFormat::Macho) => {
+                "e-m:o-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-n32:64-S128-Fn32"
+            }
+            (Architecture::Aarch64(Aarch64Architecture::Aarch64), BinaryFormat::Coff) => {
+                "e-m:w-p270:32:32-p271:32:32-p272:64:64-p:64:64-i32:32-i64:64-i128:128-n32:64-S128-Fn32"
+            }
+            (Architecture::Arm(_), BinaryFormat::Elf) if big_endian => {
+                "E-m:e-p:32:32-Fi8-i64:64-v128:64:128-a:0:32-n32-S64"
+            }
+            (Architecture::Arm(_), BinaryFormat::Elf) => {
+                "e-m:e-p:32:32-Fi8-i64:64-v128:64:128-a:0:32-n32-S64"
+            }
+            (Architecture::Arm(_), BinaryFormat::Macho) if !big_endian => {
+                "e-m:o-p:32:32-Fi8-f64:32:64-v64:32:64-v128:32:128-a:0:32-n32-S32"
+            }
+            (Architecture::Arm(_), BinaryFormat::Coff) if !big_endian => {
+                "e-m:w-p:32:32-Fi8-i64:64-v128:64:128-a:0:32-n32-S64"
+            }
+            (Architecture::Riscv(riscv), BinaryFormat::Elf)
+                if riscv.base_width() == PointerWidth::U32 => "e-m:e-p:32:32-i64:64-n32-S128",
+            (Architecture::Riscv(riscv), BinaryFormat::Elf)
+                if riscv.base_width() == PointerWidth::U64 =>
+            {
+                "e-m:e-p:64:64-i64:64-i128:128-n32:64-S128"
+            }
+            (Architecture::Mips32(_), BinaryFormat::Elf) if big_endian => {
+                "E-m:m-p:32:32-i8:8:32-i16:16:32-i64:64-n32-S64"
+            }
+            (Architecture::Mips32(_), BinaryFormat::Elf) => {
+                "e-m:m-p:32:32-i8:8:32-i16:16:32-i64:64-n32-S64"
+            }
+            (Architecture::Mips64(_), BinaryFormat::Elf) if big_endian => {
+                "E-m:e-i8:8:32-i16:16:32-i64:64-n32:64-S128"
+            }
+            (Architecture::Mips64(_), BinaryFormat::Elf) => {
+                "e-m:e-i8:8:32-i16:16:32-i64:64-n32:64-S128"
+            }
+            (Architecture::Powerpc, BinaryFormat::Elf) => "E-m:e-p:32:32-Fn32-i64:64-n32",
+            (Architecture::Powerpc64, BinaryFormat::Elf) => {
+                "E-m:e-Fi64-i64:64-i128:128-n32:64-S128-v256:256:256-v512:512:512"
+            }
+            (Architecture::Powerpc64le, BinaryFormat::Elf) => {
+                "e-m:e-Fn32-i64:64-i128:128-n32:64-S128-v256:256:256-v512:512:512"
+            }
+            (Architecture::S390x, BinaryFormat::Elf) => {
+                "E-S64-i1:8:16-i8:8:16-i64:64-f128:64-v128:64-a:8:16-n32:64"
+            }
+            (Architecture::Sparc, BinaryFormat::Elf) => {
+                "E-m:e-p:32:32-i64:64-i128:128-f128:64-n32-S64"
+            }
+            (Architecture::Sparc64, BinaryFormat::Elf)
+            | (Architecture::Sparcv9, BinaryFormat::Elf) => "E-m:e-i64:64-i128:128-n32:64-S128",
+            (Architecture::Wasm32, BinaryFormat::Wasm) => {
+                "e-m:e-p:32:32-p10:8:8-p20:8:8-i64:64-i128:128-n32:64-S128-ni:1:10:20"
+            }
+            (Architecture::Wasm64, BinaryFormat::Wasm) => {
+                "e-m:e-p:64:64-p10:8:8-p20:8:8-i64:64-i128:128-n32:64-S128-ni:1:10:20"
+            }
+            (Architecture::Msp430, BinaryFormat::Elf) => {
+                "e-m:e-p:16:16-i32:16-i64:16-f32:16-f64:16-a:8-n8:16-S16"
+            }
+            (Architecture::M68k, BinaryFormat::Elf) => {
+                "E-m:e-p:32:16:32-i8:8:8-i16:16:16-i32:16:32-n8:16:32-a:0:16-S16"
+            }
+            (Architecture::Hexagon, BinaryFormat::Elf) => {
+                "e-m:e-p:32:32:32-a:0-n16:32-i64:64:64-i32:32:32-i16:16:16-i1:8:8-f32:32:32-f64:64:64-v32:32:32-v64:64:64-v512:512:512-v1024:1024:1024-v2048:2048:2048"
+            }
+            (Architecture::Nvptx64, _) => "e-i64:64-i128:128-v16:16-v32:32-n16:32:64",
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Rewrite a component of an LLVM triple, other than the architecture, into
+/// the form `Triple`'s parser expects.
+fn normalize_llvm_part(part: &str) -> String {
+    if part == "simulator" {
+        return "sim".to_owned();
+    }
+    if let Some(version) = part.strip_prefix("macosx") {
+        // `OperatingSystem::MacOSX` requires all three version numbers.
+        let numbers = version.split('.').filter(|s| !s.is_empty()).count();
+        return match numbers {
+            0 => "darwin".to_owned(),
+            1 => format!("{}.0.0", part),
+            2 => format!("{}.0", part),
+            _ => part.to_owned(),
+        };
+    }
+    // Drop the versions LLVM allows on operating systems and environments,
+    // as in `x86_64-unknown-freebsd13.2`, `aarch64-linux-android21`, or
+    // `x86_64-pc-windows-msvc19.29.30133`.
+    if OperatingSystem::from_str(part).is_err() && Environment::from_str(part).is_err() {
+        let name = part.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+        if name != part
+            && (OperatingSystem::from_str(name).is_ok() || Environment::from_str(name).is_ok())
+        {
+            return name.to_owned();
+        }
+    }
+    part.to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_llvm_triples() {
+        let pairs = [
+            ("x86_64-unknown-linux-gnu", "x86_64-unknown-linux-gnu"),
+            ("aarch64-apple-darwin", "arm64-apple-macosx"),
+            ("x86_64-apple-macosx10.7.0", "x86_64-apple-macosx10.7.0"),
+            ("aarch64-apple-ios-sim", "arm64-apple-ios-simulator"),
+            ("aarch64-linux-android", "aarch64-unknown-linux-android"),
+            ("thumbv7em-none-eabihf", "thumbv7em-unknown-none-eabihf"),
+            (
+                "thumbv7neon-unknown-linux-gnueabihf",
+                "armv7-unknown-linux-gnueabihf",
+            ),
+            ("riscv64gc-unknown-linux-gnu", "riscv64-unknown-linux-gnu"),
+            ("riscv32imac-unknown-none-elf", "riscv32-unknown-none-elf"),
+            ("x86_64-pc-windows-gnullvm", "x86_64-pc-windows-gnu"),
+            ("x86_64-pc-windows-msvc.vc143.mt", "x86_64-pc-windows-msvc"),
+            ("wasm32-unknown-unknown", "wasm32-unknown-unknown"),
+            ("x86_64-sony-ps4", "x86_64-scei-ps4"),
+            ("x86_64-sony-ps5", "x86_64-sie-ps5"),
+            ("riscv32imc-esp-espidf", "riscv32-esp-espidf"),
+            ("xtensa-esp32s3-none-elf", "xtensa-esp-none-elf"),
+            (
+                "dxil-pc-shadermodel6.6-compute",
+                "dxil-pc-shadermodel6.6-compute",
+            ),
+        ];
+        for (triple, llvm) in pairs.iter() {
+            assert_eq!(Triple::from_str(triple).unwrap().to_llvm_triple(), *llvm);
+        }
+    }
+
+    #[test]
+    fn from_llvm_triples() {
+        let pairs = [
+            ("x86_64-unknown-linux-gnu", "x86_64-unknown-linux-gnu"),
+            ("arm64-apple-macosx", "aarch64-apple-darwin"),
+            ("arm64-apple-macosx11.0.0", "aarch64-apple-macosx11.0.0"),
+            ("x86_64-apple-macosx10.15", "x86_64-apple-macosx10.15.0"),
+            ("x86_64-apple-macosx11", "x86_64-apple-macosx11.0.0"),
+            ("x86_64-apple-darwin19.6.0", "x86_64-apple-darwin"),
+            ("arm64-apple-ios14.0", "aarch64-apple-ios"),
+            ("arm64-apple-ios14.0-simulator", "aarch64-apple-ios-sim"),
+            ("x86_64-linux-gnu", "x86_64-unknown-linux-gnu"),
+            ("thumbv7em-none-eabi", "thumbv7em-none-eabi"),
+            ("x86_64-unknown-freebsd13.2", "x86_64-unknown-freebsd"),
+            ("aarch64-linux-android21", "aarch64-linux-android"),
+            (
+                "x86_64-pc-windows-msvc19.29.30133",
+                "x86_64-pc-windows-msvc",
+            ),
+            (
+                "dxil-pc-shadermodel6.3-library",
+                "dxil-pc-shadermodel6.3-library",
+            ),
+            ("x86_64-scei-ps4", "x86_64-scei-ps4"),
+            ("x86_64-sie-ps5", "x86_64-sie-ps5"),
+            ("xtensa-esp-none-elf", "xtensa-esp-none-elf"),
+        ];
+        for (llvm, triple) in pairs.iter() {
+            assert_eq!(
+                Triple::from_llvm_triple(llvm),
+                Ok(Triple::from_str(triple).unwrap()),
+                "{}",
+                llvm
+            );
+        }
+        assert!(Triple::from_llvm_triple("x86_64-unknown-nope1.0").is_err());
+
+        for vector in crate::test_vectors::TEST_VECTORS {
+            let triple = Triple::from_str(vector.triple).unwrap();
+            let llvm = triple.to_llvm_triple();
+            assert!(Triple::from_llvm_triple(&llvm).is_ok(), "{}", llvm);
+        }
+    }
+
+    #[test]
+    fn llvm_data_layouts() {
+        let triple = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(
+            triple.llvm_data_layout(),
+            Ok("e-m:e-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-f80:128-n8:16:32:64-S128")
+        );
+        let triple = Triple::from_str("aarch64-apple-darwin").unwrap();
+        assert_eq!(
+            triple.llvm_data_layout(),
+            Ok("e-m:o-p270:32:32-p271:32:32-p272:64:64-i64:64-i128:128-n32:64-S128-Fn32")
+        );
+        let triple = Triple::from_str("aarch64-unknown-linux-gnu_ilp32").unwrap();
+        assert_eq!(triple.llvm_data_layout(), Err(()));
+        let triple = Triple::from_str("xtensa-esp32-espidf").unwrap();
+        assert_eq!(triple.llvm_data_layout(), Err(()));
+
+        // Every known layout should agree with the triple's endianness and
+        // pointer width.
+        for vector in crate::test_vectors::TEST_VECTORS {
+            let triple = Triple::from_str(vector.triple).unwrap();
+            let layout = match triple.llvm_data_layout() {
+                Ok(layout) => layout,
+                Err(()) => continue,
+            };
+            let big = layout.starts_with('E');
+            assert_eq!(
+                triple.endianness(),
+                Ok(if big {
+                    Endianness::Big
+                } else {
+                    Endianness::Little
+                }),
+                "{}",
+                vector.triple
+            );
+            let bits = triple.pointer_width().unwrap().bits();
+            let pointer = format!("p:{}:", bits);
+            assert!(
+                layout.contains(&pointer)
+                    || (bits == 64 && !layout.contains("p:32:") && !layout.contains("p:16:")),
+                "{}",
+                vector.triple
+            );
+        }
+    }
+}
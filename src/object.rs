@@ -0,0 +1,199 @@
+// This file defines conversions between this crate's types and the
+// corresponding types in the `object` crate.
+
+use crate::targets::{
+    Aarch64Architecture, Architecture, ArmArchitecture, BinaryFormat, Mips32Architecture,
+    Mips64Architecture, RiscvArchitecture, X86_32Architecture,
+};
+use crate::triple::{Endianness, PointerWidth};
+use core::convert::TryFrom;
+
+impl TryFrom<Architecture> for ::object::Architecture {
+    type Error = ();
+
+    /// Fails for architectures `object` doesn't support. Variants which only
+    /// differ in sub-architecture or endianness convert to the same
+    /// `object` architecture.
+    fn try_from(architecture: Architecture) -> Result<Self, ()> {
+        use ::object::Architecture as Object;
+
+        Ok(match architecture {
+            Architecture::Unknown => Object::Unknown,
+            Architecture::Arm(_) => Object::Arm,
+            Architecture::Aarch64(_) => Object::Aarch64,
+            Architecture::Hexagon => Object::Hexagon,
+            Architecture::X86_32(_) => Object::I386,
+            Architecture::M68k => Object::M68k,
+            Architecture::Mips32(_) => Object::Mips,
+            Architecture::Mips64(_) => Object::Mips64,
+            Architecture::Msp430 => Object::Msp430,
+            Architecture::Powerpc => Object::PowerPc,
+            Architecture::Powerpc64 | Architecture::Powerpc64le => Object::PowerPc64,
+            Architecture::Riscv(riscv) => match riscv.base_width() {
+                PointerWidth::U64 => Object::Riscv64,
+                _ => Object::Riscv32,
+            },
+            Architecture::S390x => Object::S390x,
+            Architecture::Sparc => Object::Sparc,
+            Architecture::Sparc64 | Architecture::Sparcv9 => Object::Sparc64,
+            Architecture::Wasm32 => Object::Wasm32,
+            Architecture::Wasm64 => Object::Wasm64,
+            Architecture::X86_64 => Object::X86_64,
+            Architecture::Xtensa => Object::Xtensa,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<::object::Architecture> for Architecture {
+    type Error = ();
+
+    /// Fails for architectures this crate doesn't support. Each `object`
+    /// architecture converts to the generic variant of its family, and ILP32
+    /// variants, which this crate describes with an `Environment`, convert to
+    /// their 64-bit architecture.
+    fn try_from(architecture: ::object::Architecture) -> Result<Self, ()> {
+        use ::object::Architecture as Object;
+
+        Ok(match architecture {
+            Object::Unknown => Architecture::Unknown,
+            Object::Aarch64 | Object::Aarch64_Ilp32 => {
+                Architecture::Aarch64(Aarch64Architecture::Aarch64)
+            }
+            Object::Arm => Architecture::Arm(ArmArchitecture::Arm),
+            Object::I386 => Architecture::X86_32(X86_32Architecture::I386),
+            Object::X86_64 | Object::X86_64_X32 => Architecture::X86_64,
+            Object::Hexagon => Architecture::Hexagon,
+            Object::M68k => Architecture::M68k,
+            Object::Mips => Architecture::Mips32(Mips32Architecture::Mips),
+            Object::Mips64 | Object::Mips64_N32 => Architecture::Mips64(Mips64Architecture::Mips64),
+            Object::Msp430 => Architecture::Msp430,
+            Object::PowerPc => Architecture::Powerpc,
+            Object::PowerPc64 => Architecture::Powerpc64,
+            Object::Riscv32 => Architecture::Riscv(RiscvArchitecture::riscv32()),
+            Object::Riscv64 => Architecture::Riscv(RiscvArchitecture::riscv64()),
+            Object::S390x => Architecture::S390x,
+            Object::Sparc | Object::Sparc32Plus => Architecture::Sparc,
+            Object::Sparc64 => Architecture::Sparc64,
+            Object::Wasm32 => Architecture::Wasm32,
+            Object::Wasm64 => Architecture::Wasm64,
+            Object::Xtensa => Architecture::Xtensa,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<BinaryFormat> for ::object::BinaryFormat {
+    type Error = ();
+
+    /// Fails for formats `object` doesn't support, and for `Unknown`.
+    fn try_from(binary_format: BinaryFormat) -> Result<Self, ()> {
+        use ::object::BinaryFormat as Object;
+
+        Ok(match binary_format {
+            BinaryFormat::Elf => Object::Elf,
+            BinaryFormat::Coff => Object::Coff,
+            BinaryFormat::Macho => Object::MachO,
+            BinaryFormat::Wasm => Object::Wasm,
+            BinaryFormat::Xcoff => Object::Xcoff,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl TryFrom<::object::BinaryFormat> for BinaryFormat {
+    type Error = ();
+
+    /// Fails for formats this crate doesn't support. PE images convert to
+    /// `Coff`, which this crate uses for both objects and images.
+    fn try_from(binary_format: ::object::BinaryFormat) -> Result<Self, ()> {
+        use ::object::BinaryFormat as Object;
+
+        Ok(match binary_format {
+            Object::Coff | Object::Pe => BinaryFormat::Coff,
+            Object::Elf => BinaryFormat::Elf,
+            Object::MachO => BinaryFormat::Macho,
+            Object::Wasm => BinaryFormat::Wasm,
+            Object::Xcoff => BinaryFormat::Xcoff,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl From<Endianness> for ::object::Endianness {
+    fn from(endianness: Endianness) -> Self {
+        match endianness {
+            Endianness::Little => ::object::Endianness::Little,
+            Endianness::Big => ::object::Endianness::Big,
+        }
+    }
+}
+
+impl From<::object::Endianness> for Endianness {
+    fn from(endianness: ::object::Endianness) -> Self {
+        match endianness {
+            ::object::Endianness::Little => Endianness::Little,
+            ::object::Endianness::Big => Endianness::Big,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Triple;
+    use core::str::FromStr;
+
+    #[test]
+    fn architectures() {
+        let triple = Triple::from_str("armv7-unknown-linux-gnueabihf").unwrap();
+        assert_eq!(
+            ::object::Architecture::try_from(triple.architecture),
+            Ok(::object::Architecture::Arm)
+        );
+        assert_eq!(
+            ::object::Architecture::try_from(Architecture::Nvptx64),
+            Err(())
+        );
+
+        for architecture in [
+            Architecture::X86_64,
+            Architecture::Aarch64(Aarch64Architecture::Aarch64),
+            Architecture::Riscv(RiscvArchitecture::riscv64()),
+            Architecture::Wasm32,
+        ]
+        .iter()
+        {
+            let object = ::object::Architecture::try_from(*architecture).unwrap();
+            assert_eq!(Architecture::try_from(object), Ok(*architecture));
+        }
+    }
+
+    #[test]
+    fn binary_formats() {
+        assert_eq!(
+            ::object::BinaryFormat::try_from(BinaryFormat::Macho),
+            Ok(::object::BinaryFormat::MachO)
+        );
+        assert_eq!(
+            BinaryFormat::try_from(::object::BinaryFormat::Pe),
+            Ok(BinaryFormat::Coff)
+        );
+        assert_eq!(
+            ::object::BinaryFormat::try_from(BinaryFormat::Ihex),
+            Err(())
+        );
+    }
+
+    #[test]
+    fn endianness() {
+        assert_eq!(
+            ::object::Endianness::from(Endianness::Big),
+            ::object::Endianness::Big
+        );
+        assert_eq!(
+            Endianness::from(::object::Endianness::Little),
+            Endianness::Little
+        );
+    }
+}
@@ -0,0 +1,205 @@
+// This file implements `Serialize` and `Deserialize` for `Triple` and its
+// component types, using their string forms so that serialized configs stay
+// human-readable.
+
+use crate::data_model::CDataModel;
+use crate::targets::{
+    Architecture, BinaryFormat, CustomVendor, Environment, OperatingSystem, Vendor,
+};
+use crate::triple::{CallingConvention, Endianness, PointerWidth, Triple};
+use ::serde::de::{self, Deserialize, Deserializer, Visitor};
+use ::serde::ser::{Serialize, Serializer};
+use alloc::boxed::Box;
+use alloc::string::ToString;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// A `Visitor` which parses a string with `FromStr`.
+struct FromStrVisitor<T>(&'static str, PhantomData<T>);
+
+impl<'de, T: FromStr> Visitor<'de> for FromStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        T::from_str(s).map_err(|_| E::invalid_value(de::Unexpected::Str(s), &self))
+    }
+}
+
+/// Implement `Serialize` with `Display` and `Deserialize` with `FromStr`.
+macro_rules! serde_via_str {
+    ($($ty:ty => $expecting:expr,)*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserializer.deserialize_str(FromStrVisitor($expecting, PhantomData))
+                }
+            }
+        )*
+    };
+}
+
+serde_via_str! {
+    Triple => "a target triple",
+    Architecture => "an architecture name",
+    Vendor => "a vendor name",
+    OperatingSystem => "an operating system name",
+    Environment => "an environment name",
+    BinaryFormat => "a binary format name",
+}
+
+/// Implement `Serialize` and `Deserialize` for a fieldless enum which has no
+/// `Display` form, using the given lowercase names.
+macro_rules! serde_via_names {
+    ($($ty:ident => $expecting:expr, { $($variant:ident => $name:expr,)* })*) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(match self {
+                        $($ty::$variant => $name,)*
+                    })
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    struct NameVisitor;
+
+                    impl<'de> Visitor<'de> for NameVisitor {
+                        type Value = $ty;
+
+                        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                            f.write_str($expecting)
+                        }
+
+                        fn visit_str<E: de::Error>(self, s: &str) -> Result<$ty, E> {
+                            match s {
+                                $($name => Ok($ty::$variant),)*
+                                _ => Err(E::unknown_variant(s, &[$($name),*])),
+                            }
+                        }
+                    }
+
+                    deserializer.deserialize_str(NameVisitor)
+                }
+            }
+        )*
+    };
+}
+
+serde_via_names! {
+    CallingConvention => "a calling convention name", {
+        SystemV => "systemv",
+        WasmBasicCAbi => "wasmbasiccabi",
+        WindowsFastcall => "windowsfastcall",
+    }
+    Endianness => "an endianness name", {
+        Little => "little",
+        Big => "big",
+    }
+    PointerWidth => "a pointer width name", {
+        U16 => "u16",
+        U32 => "u32",
+        U64 => "u64",
+    }
+    CDataModel => "a C data model name", {
+        LP32 => "lp32",
+        ILP32 => "ilp32",
+        LLP64 => "llp64",
+        LP64 => "lp64",
+        ILP64 => "ilp64",
+    }
+}
+
+impl Serialize for CustomVendor {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CustomVendor {
+    /// Any string that's valid as the name of a custom vendor in a triple is
+    /// accepted, including ones which name a known `Vendor`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CustomVendorVisitor;
+
+        impl<'de> Visitor<'de> for CustomVendorVisitor {
+            type Value = CustomVendor;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a vendor name")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<CustomVendor, E> {
+                if !Vendor::is_custom(s.as_bytes()) {
+                    return Err(E::invalid_value(de::Unexpected::Str(s), &self));
+                }
+                Ok(CustomVendor::Owned(Box::new(s.to_string())))
+            }
+        }
+
+        deserializer.deserialize_str(CustomVendorVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip<T>(value: T, json: &str)
+    where
+        T: Serialize + for<'de> Deserialize<'de> + PartialEq + fmt::Debug,
+    {
+        assert_eq!(serde_json::to_string(&value).unwrap(), json);
+        assert_eq!(serde_json::from_str::<T>(json).unwrap(), value);
+    }
+
+    #[test]
+    fn roundtrips() {
+        roundtrip(
+            Triple::from_str("x86_64-unknown-linux-gnu").unwrap(),
+            "\"x86_64-unknown-linux-gnu\"",
+        );
+        roundtrip(Architecture::X86_64, "\"x86_64\"");
+        roundtrip(Vendor::Apple, "\"apple\"");
+        roundtrip(Vendor::Custom(CustomVendor::Static("acme")), "\"acme\"");
+        roundtrip(CustomVendor::Static("acme"), "\"acme\"");
+        roundtrip(OperatingSystem::Linux, "\"linux\"");
+        roundtrip(Environment::Musl, "\"musl\"");
+        roundtrip(BinaryFormat::Elf, "\"elf\"");
+        roundtrip(CallingConvention::WindowsFastcall, "\"windowsfastcall\"");
+        roundtrip(Endianness::Little, "\"little\"");
+        roundtrip(PointerWidth::U64, "\"u64\"");
+        roundtrip(CDataModel::LP64, "\"lp64\"");
+    }
+
+    #[test]
+    fn errors() {
+        assert!(serde_json::from_str::<Triple>("\"x86_64-unknown-nope\"").is_err());
+        assert!(serde_json::from_str::<Architecture>("\"nope\"").is_err());
+        assert!(serde_json::from_str::<Endianness>("\"middle\"").is_err());
+        assert!(serde_json::from_str::<PointerWidth>("64").is_err());
+        for name in ["\"\"", "\"linux\"", "\"a-b\"", "\"Acme\""].iter() {
+            assert!(
+                serde_json::from_str::<CustomVendor>(name).is_err(),
+                "{}",
+                name
+            );
+        }
+
+        let message = serde_json::from_str::<CDataModel>("\"lp16\"")
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("lp16"), "{}", message);
+    }
+}
@@ -1,10 +1,11 @@
 // This file defines all the identifier enums and target-aware logic.
 
-use crate::triple::{Endianness, PointerWidth, Triple};
+use crate::triple::{Bitness, Endianness, PointerWidth, Triple};
 use alloc::boxed::Box;
 use alloc::string::String;
 use core::fmt;
 use core::hash::{Hash, Hasher};
+use core::mem;
 use core::str::FromStr;
 
 /// The "architecture" field, which in some cases also specifies a specific
@@ -18,8 +19,10 @@ pub enum Architecture {
     AmdGcn,
     Aarch64(Aarch64Architecture),
     Asmjs,
+    Dxil,
     Hexagon,
     X86_32(X86_32Architecture),
+    M68k,
     Mips32(Mips32Architecture),
     Mips64(Mips64Architecture),
     Msp430,
@@ -27,15 +30,19 @@ pub enum Architecture {
     Powerpc,
     Powerpc64,
     Powerpc64le,
-    Riscv32(Riscv32Architecture),
-    Riscv64(Riscv64Architecture),
+    /// A 32- or 64-bit RISC-V architecture.
+    Riscv(RiscvArchitecture),
     S390x,
     Sparc,
     Sparc64,
     Sparcv9,
+    Spirv,
+    Spirv32,
+    Spirv64,
     Wasm32,
     Wasm64,
     X86_64,
+    Xtensa,
 }
 
 #[non_exhaustive]
@@ -118,7 +125,7 @@ pub enum Aarch64Architecture {
 
 impl ArmArchitecture {
     /// Test if this architecture uses the Thumb instruction set.
-    pub fn is_thumb(self) -> bool {
+    pub const fn is_thumb(self) -> bool {
         match self {
             ArmArchitecture::Arm
             | ArmArchitecture::Armeb
@@ -167,7 +174,7 @@ impl ArmArchitecture {
     // }
 
     /// Return the pointer bit width of this target's architecture.
-    pub fn pointer_width(self) -> PointerWidth {
+    pub const fn pointer_width(self) -> PointerWidth {
         match self {
             ArmArchitecture::Arm
             | ArmArchitecture::Armeb
@@ -212,7 +219,7 @@ impl ArmArchitecture {
     }
 
     /// Return the endianness of this architecture.
-    pub fn endianness(self) -> Endianness {
+    pub const fn endianness(self) -> Endianness {
         match self {
             ArmArchitecture::Arm
             | ArmArchitecture::Armv4
@@ -259,7 +266,7 @@ impl ArmArchitecture {
 
 impl Aarch64Architecture {
     /// Test if this architecture uses the Thumb instruction set.
-    pub fn is_thumb(self) -> bool {
+    pub const fn is_thumb(self) -> bool {
         match self {
             Aarch64Architecture::Aarch64 | Aarch64Architecture::Aarch64be => false,
         }
@@ -270,14 +277,14 @@ impl Aarch64Architecture {
     // }
 
     /// Return the pointer bit width of this target's architecture.
-    pub fn pointer_width(self) -> PointerWidth {
+    pub const fn pointer_width(self) -> PointerWidth {
         match self {
             Aarch64Architecture::Aarch64 | Aarch64Architecture::Aarch64be => PointerWidth::U64,
         }
     }
 
     /// Return the endianness of this architecture.
-    pub fn endianness(self) -> Endianness {
+    pub const fn endianness(self) -> Endianness {
         match self {
             Aarch64Architecture::Aarch64 => Endianness::Little,
             Aarch64Architecture::Aarch64be => Endianness::Big,
@@ -285,27 +292,143 @@ impl Aarch64Architecture {
     }
 }
 
-/// An enum for all 32-bit RISC-V architectures.
-#[non_exhaustive]
+impl RiscvArchitecture {
+    /// Return the 32-bit base architecture with no extensions, `riscv32`.
+    pub const fn riscv32() -> Self {
+        Self {
+            base_width: PointerWidth::U32,
+            extensions: 0,
+        }
+    }
+
+    /// Return the 64-bit base architecture with no extensions, `riscv64`.
+    pub const fn riscv64() -> Self {
+        Self {
+            base_width: PointerWidth::U64,
+            extensions: 0,
+        }
+    }
+
+    /// Return this architecture with the given extension added.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the extension is `E` and the architecture has `I`, or the
+    /// other way around, as the two base ISAs are exclusive. Use
+    /// `checked_with_extension` to handle that case.
+    pub const fn with_extension(self, extension: RiscvExtension) -> Self {
+        match self.checked_with_extension(extension) {
+            Some(riscv) => riscv,
+            None => panic!("the I and E RISC-V base ISAs are exclusive"),
+        }
+    }
+
+    /// Return this architecture with the given extension added, or `None`
+    /// if the extension is `E` and the architecture has `I`, or the other
+    /// way around.
+    pub const fn checked_with_extension(self, extension: RiscvExtension) -> Option<Self> {
+        let conflict = match extension {
+            RiscvExtension::I => self.has_extension(RiscvExtension::E),
+            RiscvExtension::E => self.has_extension(RiscvExtension::I),
+            _ => false,
+        };
+        if conflict {
+            return None;
+        }
+        Some(Self {
+            base_width: self.base_width,
+            extensions: self.extensions | (1 << extension as u16),
+        })
+    }
+
+    /// Test whether this architecture includes the given extension. `G` is
+    /// shorthand, so `riscv64gc` has `I`, `M`, `A`, `F`, `D` and `C`.
+    pub const fn has_extension(self, extension: RiscvExtension) -> bool {
+        self.extensions & (1 << extension as u16) != 0
+    }
+
+    /// Return the extensions in this architecture, in canonical order.
+    pub fn extensions(self) -> impl Iterator<Item = RiscvExtension> {
+        RISCV_EXTENSIONS
+            .iter()
+            .copied()
+            .filter(move |extension| self.has_extension(*extension))
+    }
+
+    /// Return the width of the base integer ISA, which is also the pointer
+    /// width.
+    pub const fn base_width(self) -> PointerWidth {
+        self.base_width
+    }
+}
+
+impl RiscvExtension {
+    const fn letter(self) -> char {
+        match self {
+            RiscvExtension::I => 'i',
+            RiscvExtension::E => 'e',
+            RiscvExtension::M => 'm',
+            RiscvExtension::A => 'a',
+            RiscvExtension::F => 'f',
+            RiscvExtension::D => 'd',
+            RiscvExtension::Q => 'q',
+            RiscvExtension::C => 'c',
+            RiscvExtension::B => 'b',
+            RiscvExtension::V => 'v',
+            RiscvExtension::H => 'h',
+        }
+    }
+}
+
+/// A RISC-V architecture: a base integer ISA, of either 32 or 64 bits, plus
+/// a set of single-letter standard extensions, as in `riscv64gc` or
+/// `riscv32imafc`.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
-#[allow(missing_docs)]
-pub enum Riscv32Architecture {
-    Riscv32,
-    Riscv32i,
-    Riscv32imac,
-    Riscv32imc,
+pub struct RiscvArchitecture {
+    base_width: PointerWidth,
+    extensions: u16,
 }
 
-/// An enum for all 64-bit RISC-V architectures.
+/// A single-letter RISC-V standard extension, or base ISA, in the canonical
+/// order extensions are written in ISA strings.
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 #[allow(missing_docs)]
-pub enum Riscv64Architecture {
-    Riscv64,
-    Riscv64gc,
-    Riscv64imac,
+pub enum RiscvExtension {
+    I,
+    E,
+    M,
+    A,
+    F,
+    D,
+    Q,
+    C,
+    B,
+    V,
+    H,
 }
 
+const RISCV_EXTENSIONS: [RiscvExtension; 11] = [
+    RiscvExtension::I,
+    RiscvExtension::E,
+    RiscvExtension::M,
+    RiscvExtension::A,
+    RiscvExtension::F,
+    RiscvExtension::D,
+    RiscvExtension::Q,
+    RiscvExtension::C,
+    RiscvExtension::B,
+    RiscvExtension::V,
+    RiscvExtension::H,
+];
+
+/// The extensions the `G` shorthand stands for.
+const RISCV_G: u16 = (1 << RiscvExtension::I as u16)
+    | (1 << RiscvExtension::M as u16)
+    | (1 << RiscvExtension::A as u16)
+    | (1 << RiscvExtension::F as u16)
+    | (1 << RiscvExtension::D as u16);
+
 /// An enum for all 32-bit x86 architectures.
 #[non_exhaustive]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -343,6 +466,7 @@ pub enum Mips64Architecture {
 #[derive(Clone, Debug, Eq)]
 pub enum CustomVendor {
     /// An owned `String`. This supports the general case.
+    #[allow(clippy::box_collection)]
     Owned(Box<String>),
     /// A static `str`, so that `CustomVendor` can be constructed in `const`
     /// contexts.
@@ -380,13 +504,25 @@ pub enum Vendor {
     Unknown,
     Amd,
     Apple,
+    /// Espressif, spelled `esp` or after the chip family, as rustc does for
+    /// its Xtensa targets.
+    Espressif(EspressifVendor),
     Experimental,
     Fortanix,
+    Ibm,
+    Kmc,
+    Mti,
+    Nintendo,
     Nvidia,
+    OpenWrt,
     Pc,
     Rumprun,
+    /// Sony, which a `Triple` spells `scei` on the PS4 and `sie` on the PS5.
+    Sony,
     Sun,
+    Unikraft,
     Uwp,
+    Win7,
     Wrs,
 
     /// A custom vendor. "Custom" in this context means that the vendor is
@@ -399,6 +535,20 @@ pub enum Vendor {
     Custom(CustomVendor),
 }
 
+/// The spelling of the Espressif vendor.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum EspressifVendor {
+    /// `esp`, which is also what `espressif` parses as.
+    Esp,
+    /// `esp32`.
+    Esp32,
+    /// `esp32s2`.
+    Esp32s2,
+    /// `esp32s3`.
+    Esp32s3,
+}
+
 /// The "operating system" field, which sometimes implies an environment, and
 /// sometimes isn't an actual operating system.
 #[non_exhaustive]
@@ -406,13 +556,16 @@ pub enum Vendor {
 #[allow(missing_docs)]
 pub enum OperatingSystem {
     Unknown,
+    Aix,
     AmdHsa,
+    AmdPal,
     Bitrig,
     Cloudabi,
     Cuda,
     Darwin,
     Dragonfly,
     Emscripten,
+    Espidf,
     Freebsd,
     Fuchsia,
     Haiku,
@@ -420,17 +573,42 @@ pub enum OperatingSystem {
     Ios,
     L4re,
     Linux,
-    MacOSX { major: u16, minor: u16, patch: u16 },
+    MacOSX {
+        major: u16,
+        minor: u16,
+        patch: u16,
+    },
+    Managarm,
     Nebulet,
     Netbsd,
+    Nintendo3ds,
     None_,
+    Nuttx,
     Openbsd,
+    Ps4,
+    Ps5,
+    Psp,
+    Psx,
     Redox,
+    Rtems,
+    /// A DirectX shader model, as in LLVM's `dxil-pc-shadermodel6.3-library`.
+    ShaderModel {
+        major: u16,
+        minor: u16,
+    },
     Solaris,
+    SolidAsp3,
+    Switch,
+    Tvos,
     Uefi,
+    Vita,
     VxWorks,
     Wasi,
+    WasiP2,
+    WasiP3,
+    Watchos,
     Windows,
+    Zos,
 }
 
 /// The "environment" field, which specifies an ABI environment on top of the
@@ -447,21 +625,124 @@ pub enum Environment {
     Eabi,
     Eabihf,
     Gnu,
+    Gnullvm,
+    /// MinGW linking against the Universal C Runtime.
+    GnuUcrt,
+    /// MinGW linking against the legacy `msvcrt.dll`.
+    GnuMsvcrt,
     Gnuabi64,
     Gnueabi,
     Gnueabihf,
+    GnuIlp32,
     Gnuspe,
     Gnux32,
     Musl,
     Musleabi,
     Musleabihf,
     Muslabi64,
+    Relibc,
+    Mlibc,
     Msvc,
+    /// The MSVC environment, with the toolset that built the code and/or the
+    /// way the C runtime is linked. Artifacts that differ in these aren't
+    /// necessarily link-compatible, even though their triples are otherwise
+    /// identical.
+    MsvcVersioned(MsvcVersion),
     Kernel,
+    Freestanding,
+    /// Mac Catalyst: the iOS API surface, running on macOS.
+    MacABI,
+    Sim,
+    Newlib,
+    Newlibeabi,
+    Newlibeabihf,
     Uclibc,
     Sgx,
     Softfloat,
     Spe,
+    /// A DirectX pixel shader.
+    Pixel,
+    /// A DirectX vertex shader.
+    Vertex,
+    /// A DirectX geometry shader.
+    Geometry,
+    /// A DirectX hull shader.
+    Hull,
+    /// A DirectX domain shader.
+    Domain,
+    /// A DirectX compute shader.
+    Compute,
+    /// A DirectX shader library, holding entry points for several stages.
+    Library,
+    /// A DirectX ray generation shader.
+    RayGeneration,
+    /// A DirectX intersection shader.
+    Intersection,
+    /// A DirectX any-hit shader.
+    AnyHit,
+    /// A DirectX closest-hit shader.
+    ClosestHit,
+    /// A DirectX miss shader.
+    Miss,
+    /// A DirectX callable shader.
+    Callable,
+    /// A DirectX mesh shader.
+    Mesh,
+    /// A DirectX amplification shader.
+    Amplification,
+}
+
+/// An MSVC platform toolset version.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MsvcToolset {
+    /// Visual Studio 2015.
+    Vc140,
+    /// Visual Studio 2017.
+    Vc141,
+    /// Visual Studio 2019.
+    Vc142,
+    /// Visual Studio 2022.
+    Vc143,
+}
+
+/// What an `Environment::MsvcVersioned` specifies beyond plain `msvc`. At
+/// least one of the toolset and the C runtime linkage is always present; use
+/// [`Environment::msvc`] to build an environment from optional parts.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MsvcVersion {
+    /// Only the toolset, as in `msvc.vc143`.
+    Toolset(MsvcToolset),
+    /// Only the C runtime linkage, as in `msvc.mt`.
+    Crt(MsvcCrt),
+    /// Both, as in `msvc.vc143.mt`.
+    ToolsetAndCrt(MsvcToolset, MsvcCrt),
+}
+
+/// The C runtime a MinGW target links against. Code built against one can't
+/// safely be mixed with code built against the other.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MingwCrt {
+    /// The legacy `msvcrt.dll`.
+    Msvcrt,
+    /// The Universal C Runtime.
+    Ucrt,
+}
+
+/// How the MSVC C runtime is linked.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum MsvcCrt {
+    /// The DLL runtime, as selected by `/MD`.
+    Dynamic,
+    /// The debug DLL runtime, as selected by `/MDd`.
+    DynamicDebug,
+    /// The static runtime, as selected by `/MT`.
+    Static,
+    /// The debug static runtime, as selected by `/MTd`.
+    StaticDebug,
 }
 
 /// The "binary format" field, which is usually omitted, and the binary format
@@ -475,17 +756,124 @@ pub enum BinaryFormat {
     Coff,
     Macho,
     Wasm,
+    Xcoff,
+    Goff,
+    Spirv,
+    DxContainer,
+    /// A raw binary image, as produced by `objcopy -O binary`.
+    Raw,
+    /// An Intel HEX image.
+    Ihex,
+}
+
+/// The ABI a target uses, as distinct from its environment. This is what
+/// rustc reports as `target_abi`; for example `armv7-unknown-linux-gnueabihf`
+/// has the `gnu` environment and the `eabihf` ABI.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Abi {
+    Abi64,
+    Eabi,
+    Eabihf,
+    Fortanix,
+    Ilp32,
+    Llvm,
+    MacABI,
+    Sim,
+    Softfloat,
+    Spe,
+    Uwp,
+    X32,
+}
+
+/// The width of an architecture's vector registers.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VectorWidth {
+    /// Vectors of the given number of bits.
+    Fixed(u32),
+    /// Scalable vectors, as with SVE and RVV, whose width is only known at
+    /// run time and may be up to the given number of bits.
+    Scalable {
+        /// The largest width the architecture permits, in bits.
+        max: u32,
+    },
+}
+
+/// A coarse grouping of architectures, for code which only cares about the
+/// instruction set family and not about the particular variant or revision.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum ArchFamily {
+    Unknown,
+    /// 32-bit Arm and Thumb, and AArch64.
+    Arm,
+    Asmjs,
+    /// GPU and shader targets: AMDGCN, NVPTX, SPIR-V and DXIL.
+    Gpu,
+    Hexagon,
+    M68k,
+    Mips,
+    Msp430,
+    PowerPc,
+    RiscV,
+    S390x,
+    Sparc,
+    Wasm,
+    /// 32-bit x86 and x86-64.
+    X86,
+    Xtensa,
+}
+
+/// The kind of kernel an operating system runs on, for code that depends on
+/// kernel behavior such as system call conventions or path semantics rather
+/// than on the particular operating system.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum KernelFamily {
+    /// Linux, including Android.
+    Linux,
+    /// Windows NT.
+    Nt,
+    /// XNU, the kernel of Apple's operating systems.
+    Xnu,
+    /// One of the BSDs.
+    Bsd(Bsd),
+    /// Solaris and its derivatives.
+    Solarish,
+    /// WASI, which isn't a kernel but serves as one for its programs.
+    Wasi,
+    /// Bare metal, with no kernel at all.
+    None_,
+    /// Any other kernel, including an unknown one.
+    Other,
+}
+
+/// The BSD a `KernelFamily::Bsd` kernel belongs to.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Bsd {
+    Bitrig,
+    Dragonfly,
+    Freebsd,
+    Netbsd,
+    Openbsd,
 }
 
 impl Architecture {
     /// Return the endianness of this architecture.
-    pub fn endianness(self) -> Result<Endianness, ()> {
+    #[allow(clippy::result_unit_err)]
+    pub const fn endianness(self) -> Result<Endianness, ()> {
         match self {
             Architecture::Unknown => Err(()),
             Architecture::Arm(arm) => Ok(arm.endianness()),
             Architecture::Aarch64(aarch) => Ok(aarch.endianness()),
             Architecture::AmdGcn
             | Architecture::Asmjs
+            | Architecture::Dxil
             | Architecture::Hexagon
             | Architecture::X86_32(_)
             | Architecture::Mips64(Mips64Architecture::Mips64el)
@@ -495,15 +883,19 @@ impl Architecture {
             | Architecture::Msp430
             | Architecture::Nvptx64
             | Architecture::Powerpc64le
-            | Architecture::Riscv32(_)
-            | Architecture::Riscv64(_)
+            | Architecture::Riscv(_)
+            | Architecture::Spirv
+            | Architecture::Spirv32
+            | Architecture::Spirv64
             | Architecture::Wasm32
             | Architecture::Wasm64
-            | Architecture::X86_64 => Ok(Endianness::Little),
+            | Architecture::X86_64
+            | Architecture::Xtensa => Ok(Endianness::Little),
             Architecture::Mips32(Mips32Architecture::Mips)
             | Architecture::Mips64(Mips64Architecture::Mips64)
             | Architecture::Mips32(Mips32Architecture::Mipsisa32r6)
             | Architecture::Mips64(Mips64Architecture::Mipsisa64r6)
+            | Architecture::M68k
             | Architecture::Powerpc
             | Architecture::Powerpc64
             | Architecture::S390x
@@ -514,23 +906,28 @@ impl Architecture {
     }
 
     /// Return the pointer bit width of this target's architecture.
-    pub fn pointer_width(self) -> Result<PointerWidth, ()> {
+    #[allow(clippy::result_unit_err)]
+    pub const fn pointer_width(self) -> Result<PointerWidth, ()> {
         match self {
-            Architecture::Unknown => Err(()),
+            // Logical SPIR-V has no pointer width.
+            Architecture::Unknown | Architecture::Spirv => Err(()),
             Architecture::Msp430 => Ok(PointerWidth::U16),
             Architecture::Arm(arm) => Ok(arm.pointer_width()),
             Architecture::Aarch64(aarch) => Ok(aarch.pointer_width()),
+            Architecture::Riscv(riscv) => Ok(riscv.base_width()),
             Architecture::Asmjs
+            | Architecture::Dxil
             | Architecture::Hexagon
             | Architecture::X86_32(_)
-            | Architecture::Riscv32(_)
+            | Architecture::M68k
             | Architecture::Sparc
+            | Architecture::Spirv32
             | Architecture::Wasm32
             | Architecture::Mips32(_)
-            | Architecture::Powerpc => Ok(PointerWidth::U32),
+            | Architecture::Powerpc
+            | Architecture::Xtensa => Ok(PointerWidth::U32),
             Architecture::AmdGcn
             | Architecture::Powerpc64le
-            | Architecture::Riscv64(_)
             | Architecture::X86_64
             | Architecture::Mips64(_)
             | Architecture::Nvptx64
@@ -538,29 +935,417 @@ impl Architecture {
             | Architecture::S390x
             | Architecture::Sparc64
             | Architecture::Sparcv9
+            | Architecture::Spirv64
             | Architecture::Wasm64 => Ok(PointerWidth::U64),
         }
     }
+
+    /// Return the native word size of this architecture.
+    #[allow(clippy::result_unit_err)]
+    pub const fn bitness(self) -> Result<Bitness, ()> {
+        match self.pointer_width() {
+            Ok(PointerWidth::U16) => Ok(Bitness::U16),
+            Ok(PointerWidth::U32) => Ok(Bitness::U32),
+            Ok(PointerWidth::U64) => Ok(Bitness::U64),
+            Err(()) => Err(()),
+        }
+    }
+
+    /// Return the name GDB's `set architecture` command uses for this
+    /// architecture, or `Err(())` if GDB doesn't support it.
+    #[allow(clippy::result_unit_err)]
+    pub fn gdb_arch_name(self) -> Result<&'static str, ()> {
+        Ok(match self {
+            Architecture::Arm(arm) => match arm {
+                ArmArchitecture::Armv4 => "armv4",
+                ArmArchitecture::Armv4t => "armv4t",
+                ArmArchitecture::Armv5t => "armv5t",
+                ArmArchitecture::Armv5te => "armv5te",
+                ArmArchitecture::Armv5tej => "armv5tej",
+                ArmArchitecture::Armv6 | ArmArchitecture::Armv6j => "armv6",
+                ArmArchitecture::Armv6k => "armv6k",
+                ArmArchitecture::Armv6z | ArmArchitecture::Armv6kz => "armv6kz",
+                ArmArchitecture::Armv6t2 => "armv6t2",
+                ArmArchitecture::Armv6m | ArmArchitecture::Thumbv6m => "armv6-m",
+                // GDB has no separate name for ARMv7-M, only for ARMv7E-M.
+                ArmArchitecture::Armv7
+                | ArmArchitecture::Armv7a
+                | ArmArchitecture::Armv7ve
+                | ArmArchitecture::Armv7m
+                | ArmArchitecture::Armv7r
+                | ArmArchitecture::Armv7s
+                | ArmArchitecture::Armebv7r
+                | ArmArchitecture::Thumbv7a
+                | ArmArchitecture::Thumbv7m
+                | ArmArchitecture::Thumbv7neon => "armv7",
+                ArmArchitecture::Thumbv7em => "armv7e-m",
+                ArmArchitecture::Armv8
+                | ArmArchitecture::Armv8a
+                | ArmArchitecture::Armv8_1a
+                | ArmArchitecture::Armv8_2a
+                | ArmArchitecture::Armv8_3a
+                | ArmArchitecture::Armv8_4a
+                | ArmArchitecture::Armv8_5a => "armv8-a",
+                ArmArchitecture::Armv8r => "armv8-r",
+                ArmArchitecture::Armv8mBase | ArmArchitecture::Thumbv8mBase => "armv8-m.base",
+                ArmArchitecture::Armv8mMain | ArmArchitecture::Thumbv8mMain => "armv8-m.main",
+                ArmArchitecture::Arm | ArmArchitecture::Armeb | ArmArchitecture::Thumbeb => "arm",
+            },
+            Architecture::Aarch64(_) => "aarch64",
+            Architecture::X86_32(_) => "i386",
+            Architecture::M68k => "m68k",
+            Architecture::Mips32(Mips32Architecture::Mipsisa32r6)
+            | Architecture::Mips32(Mips32Architecture::Mipsisa32r6el) => "mips:isa32r6",
+            Architecture::Mips32(_) => "mips",
+            Architecture::Mips64(Mips64Architecture::Mipsisa64r6)
+            | Architecture::Mips64(Mips64Architecture::Mipsisa64r6el) => "mips:isa64r6",
+            Architecture::Mips64(_) => "mips:isa64",
+            Architecture::Msp430 => "msp:430",
+            Architecture::Powerpc => "powerpc:common",
+            Architecture::Powerpc64 | Architecture::Powerpc64le => "powerpc:common64",
+            Architecture::Riscv(riscv) => match riscv.base_width() {
+                PointerWidth::U64 => "riscv:rv64",
+                _ => "riscv:rv32",
+            },
+            Architecture::S390x => "s390:64-bit",
+            Architecture::Sparc => "sparc",
+            Architecture::Sparc64 | Architecture::Sparcv9 => "sparc:v9",
+            Architecture::X86_64 => "i386:x86-64",
+            Architecture::Xtensa => "xtensa",
+            _ => return Err(()),
+        })
+    }
+
+    /// Return the width of the widest vector registers any implementation of
+    /// this architecture has, or `None` if it has none or isn't known.
+    pub fn max_vector_width(self) -> Option<VectorWidth> {
+        Some(match self {
+            // AVX-512.
+            Architecture::X86_32(_) | Architecture::X86_64 => VectorWidth::Fixed(512),
+            // SVE.
+            Architecture::Aarch64(_) => VectorWidth::Scalable { max: 2048 },
+            // Advanced SIMD.
+            Architecture::Arm(ArmArchitecture::Armv7)
+            | Architecture::Arm(ArmArchitecture::Armv7a)
+            | Architecture::Arm(ArmArchitecture::Armv7ve)
+            | Architecture::Arm(ArmArchitecture::Armv7s)
+            | Architecture::Arm(ArmArchitecture::Armv8)
+            | Architecture::Arm(ArmArchitecture::Armv8a)
+            | Architecture::Arm(ArmArchitecture::Armv8_1a)
+            | Architecture::Arm(ArmArchitecture::Armv8_2a)
+            | Architecture::Arm(ArmArchitecture::Armv8_3a)
+            | Architecture::Arm(ArmArchitecture::Armv8_4a)
+            | Architecture::Arm(ArmArchitecture::Armv8_5a)
+            | Architecture::Arm(ArmArchitecture::Armv8r)
+            | Architecture::Arm(ArmArchitecture::Thumbv7a)
+            | Architecture::Arm(ArmArchitecture::Thumbv7neon) => VectorWidth::Fixed(128),
+            // The V extension, if the architecture lists extensions and
+            // includes it or doesn't list any.
+            Architecture::Riscv(riscv) => {
+                if riscv.extensions().next().is_some() && !riscv.has_extension(RiscvExtension::V) {
+                    return None;
+                }
+                VectorWidth::Scalable { max: 65536 }
+            }
+            // HVX in 128-byte mode.
+            Architecture::Hexagon => VectorWidth::Fixed(1024),
+            // MSA, AltiVec and VSX, the z/Architecture vector facility, and
+            // WebAssembly SIMD, respectively.
+            Architecture::Mips32(_)
+            | Architecture::Mips64(_)
+            | Architecture::Powerpc
+            | Architecture::Powerpc64
+            | Architecture::Powerpc64le
+            | Architecture::S390x
+            | Architecture::Wasm32
+            | Architecture::Wasm64 => VectorWidth::Fixed(128),
+            // VIS.
+            Architecture::Sparc64 | Architecture::Sparcv9 => VectorWidth::Fixed(64),
+            _ => return None,
+        })
+    }
+
+    /// Return the width of the vector registers available with rustc's
+    /// default target features for this architecture, or `None` if there
+    /// are none.
+    pub fn baseline_vector_width(self) -> Option<VectorWidth> {
+        match self {
+            // SSE2 on x86-64, and on i686 as rustc defines it.
+            Architecture::X86_32(X86_32Architecture::I686) | Architecture::X86_64 => {
+                Some(VectorWidth::Fixed(128))
+            }
+            // Advanced SIMD, where it isn't optional.
+            Architecture::Aarch64(_)
+            | Architecture::Arm(ArmArchitecture::Armv7s)
+            | Architecture::Arm(ArmArchitecture::Thumbv7neon) => Some(VectorWidth::Fixed(128)),
+            // VSX, which POWER8 and later have.
+            Architecture::Powerpc64le => Some(VectorWidth::Fixed(128)),
+            // The V extension, where the architecture includes it.
+            Architecture::Riscv(riscv) if riscv.has_extension(RiscvExtension::V) => {
+                Some(VectorWidth::Scalable { max: 65536 })
+            }
+            _ => None,
+        }
+    }
+
+    /// Return the family this architecture belongs to.
+    pub fn family(self) -> ArchFamily {
+        match self {
+            Architecture::Unknown => ArchFamily::Unknown,
+            Architecture::Arm(_) | Architecture::Aarch64(_) => ArchFamily::Arm,
+            Architecture::Asmjs => ArchFamily::Asmjs,
+            Architecture::AmdGcn
+            | Architecture::Dxil
+            | Architecture::Nvptx64
+            | Architecture::Spirv
+            | Architecture::Spirv32
+            | Architecture::Spirv64 => ArchFamily::Gpu,
+            Architecture::Hexagon => ArchFamily::Hexagon,
+            Architecture::M68k => ArchFamily::M68k,
+            Architecture::Mips32(_) | Architecture::Mips64(_) => ArchFamily::Mips,
+            Architecture::Msp430 => ArchFamily::Msp430,
+            Architecture::Powerpc | Architecture::Powerpc64 | Architecture::Powerpc64le => {
+                ArchFamily::PowerPc
+            }
+            Architecture::Riscv(_) => ArchFamily::RiscV,
+            Architecture::S390x => ArchFamily::S390x,
+            Architecture::Sparc | Architecture::Sparc64 | Architecture::Sparcv9 => {
+                ArchFamily::Sparc
+            }
+            Architecture::Wasm32 | Architecture::Wasm64 => ArchFamily::Wasm,
+            Architecture::X86_32(_) | Architecture::X86_64 => ArchFamily::X86,
+            Architecture::Xtensa => ArchFamily::Xtensa,
+        }
+    }
+}
+
+impl OperatingSystem {
+    /// Return the kind of kernel this operating system runs on.
+    pub fn kernel_family(self) -> KernelFamily {
+        match self {
+            OperatingSystem::Linux => KernelFamily::Linux,
+            OperatingSystem::Windows => KernelFamily::Nt,
+            OperatingSystem::Darwin
+            | OperatingSystem::Ios
+            | OperatingSystem::MacOSX { .. }
+            | OperatingSystem::Tvos
+            | OperatingSystem::Watchos => KernelFamily::Xnu,
+            OperatingSystem::Bitrig => KernelFamily::Bsd(Bsd::Bitrig),
+            OperatingSystem::Dragonfly => KernelFamily::Bsd(Bsd::Dragonfly),
+            OperatingSystem::Freebsd => KernelFamily::Bsd(Bsd::Freebsd),
+            OperatingSystem::Netbsd => KernelFamily::Bsd(Bsd::Netbsd),
+            OperatingSystem::Openbsd => KernelFamily::Bsd(Bsd::Openbsd),
+            OperatingSystem::Solaris => KernelFamily::Solarish,
+            OperatingSystem::Wasi | OperatingSystem::WasiP2 | OperatingSystem::WasiP3 => {
+                KernelFamily::Wasi
+            }
+            OperatingSystem::None_ => KernelFamily::None_,
+            _ => KernelFamily::Other,
+        }
+    }
+}
+
+impl Environment {
+    /// Test whether this is an MSVC environment, with or without toolset
+    /// information.
+    pub fn is_msvc(self) -> bool {
+        matches!(self, Environment::Msvc | Environment::MsvcVersioned(_))
+    }
+
+    /// Return the C runtime a MinGW environment links against, if that is
+    /// specified.
+    pub fn mingw_crt(self) -> Option<MingwCrt> {
+        match self {
+            Environment::GnuUcrt => Some(MingwCrt::Ucrt),
+            Environment::GnuMsvcrt => Some(MingwCrt::Msvcrt),
+            _ => None,
+        }
+    }
+
+    /// Return this environment without its ABI, C runtime, or toolset
+    /// details, as rustc reports it in `target_env`. Environments which only
+    /// describe an ABI, such as `eabihf`, have the `Unknown` base.
+    pub fn base(self) -> Environment {
+        match self {
+            Environment::Gnu
+            | Environment::Gnullvm
+            | Environment::GnuUcrt
+            | Environment::GnuMsvcrt
+            | Environment::Gnuabi64
+            | Environment::Gnueabi
+            | Environment::Gnueabihf
+            | Environment::GnuIlp32
+            | Environment::Gnuspe
+            | Environment::Gnux32 => Environment::Gnu,
+            Environment::Musl
+            | Environment::Musleabi
+            | Environment::Musleabihf
+            | Environment::Muslabi64 => Environment::Musl,
+            Environment::Newlib | Environment::Newlibeabi | Environment::Newlibeabihf => {
+                Environment::Newlib
+            }
+            Environment::Msvc | Environment::MsvcVersioned(_) => Environment::Msvc,
+            Environment::Mlibc
+            | Environment::Relibc
+            | Environment::Sgx
+            | Environment::Uclibc
+            | Environment::Unknown => self,
+            _ => Environment::Unknown,
+        }
+    }
+
+    /// Return the ABI this environment specifies, if any.
+    pub fn abi(self) -> Option<Abi> {
+        Some(match self {
+            Environment::Gnuabi64 | Environment::Muslabi64 => Abi::Abi64,
+            Environment::Androideabi
+            | Environment::Eabi
+            | Environment::Gnueabi
+            | Environment::Musleabi
+            | Environment::Newlibeabi => Abi::Eabi,
+            Environment::Eabihf
+            | Environment::Gnueabihf
+            | Environment::Musleabihf
+            | Environment::Newlibeabihf => Abi::Eabihf,
+            Environment::GnuIlp32 => Abi::Ilp32,
+            Environment::Gnullvm => Abi::Llvm,
+            Environment::MacABI => Abi::MacABI,
+            Environment::Sim => Abi::Sim,
+            Environment::Softfloat => Abi::Softfloat,
+            Environment::Gnuspe | Environment::Spe => Abi::Spe,
+            Environment::Gnux32 => Abi::X32,
+            _ => return None,
+        })
+    }
+
+    /// Return the MSVC environment with the given toolset and C runtime
+    /// linkage, which is plain `Environment::Msvc` when neither is given.
+    pub const fn msvc(toolset: Option<MsvcToolset>, crt: Option<MsvcCrt>) -> Self {
+        match (toolset, crt) {
+            (None, None) => Environment::Msvc,
+            (Some(toolset), None) => Environment::MsvcVersioned(MsvcVersion::Toolset(toolset)),
+            (None, Some(crt)) => Environment::MsvcVersioned(MsvcVersion::Crt(crt)),
+            (Some(toolset), Some(crt)) => {
+                Environment::MsvcVersioned(MsvcVersion::ToolsetAndCrt(toolset, crt))
+            }
+        }
+    }
+
+    /// Return the MSVC toolset, if one is specified.
+    pub fn msvc_toolset(self) -> Option<MsvcToolset> {
+        match self {
+            Environment::MsvcVersioned(version) => version.toolset(),
+            _ => None,
+        }
+    }
+
+    /// Return how the MSVC C runtime is linked, if that is specified.
+    pub fn msvc_crt(self) -> Option<MsvcCrt> {
+        match self {
+            Environment::MsvcVersioned(version) => version.crt(),
+            _ => None,
+        }
+    }
+}
+
+/// `strip_prefix` for byte strings, usable in `const` contexts.
+pub(crate) const fn strip_prefix<'a>(s: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+    if s.len() < prefix.len() {
+        return None;
+    }
+    let (head, tail) = s.split_at(prefix.len());
+    let mut i = 0;
+    while i < head.len() {
+        if head[i] != prefix[i] {
+            return None;
+        }
+        i += 1;
+    }
+    Some(tail)
+}
+
+/// `split_once` for byte strings, usable in `const` contexts.
+pub(crate) const fn split_once(s: &[u8], separator: u8) -> Option<(&[u8], &[u8])> {
+    let mut i = 0;
+    while i < s.len() {
+        if s[i] == separator {
+            let (head, tail) = s.split_at(i);
+            return Some((head, tail.split_at(1).1));
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Parse a decimal `u16` as `from_str` would, in a `const` context.
+const fn parse_u16(s: &[u8]) -> Result<u16, ()> {
+    let digits = match strip_prefix(s, b"+") {
+        Some(digits) => digits,
+        None => s,
+    };
+    if digits.is_empty() {
+        return Err(());
+    }
+    let mut value: u16 = 0;
+    let mut i = 0;
+    while i < digits.len() {
+        if !digits[i].is_ascii_digit() {
+            return Err(());
+        }
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((digits[i] - b'0') as u16) {
+                Some(value) => value,
+                None => return Err(()),
+            },
+            None => return Err(()),
+        };
+        i += 1;
+    }
+    Ok(value)
 }
 
 /// Return the binary format implied by this target triple, ignoring its
 /// `binary_format` field.
-pub(crate) fn default_binary_format(triple: &Triple) -> BinaryFormat {
+pub(crate) const fn default_binary_format(triple: &Triple) -> BinaryFormat {
+    // SPIR-V targets produce SPIR-V modules, whatever the environment.
+    if matches!(
+        triple.architecture,
+        Architecture::Spirv | Architecture::Spirv32 | Architecture::Spirv64
+    ) {
+        return BinaryFormat::Spirv;
+    }
+
+    // Likewise, DXIL targets produce DirectX containers.
+    if matches!(triple.architecture, Architecture::Dxil) {
+        return BinaryFormat::DxContainer;
+    }
+
     match triple.operating_system {
         OperatingSystem::None_ => match triple.environment {
-            Environment::Eabi | Environment::Eabihf => BinaryFormat::Elf,
+            Environment::Eabi
+            | Environment::Eabihf
+            | Environment::Newlib
+            | Environment::Newlibeabi
+            | Environment::Newlibeabihf => BinaryFormat::Elf,
             _ => BinaryFormat::Unknown,
         },
-        OperatingSystem::Darwin | OperatingSystem::Ios | OperatingSystem::MacOSX { .. } => {
-            BinaryFormat::Macho
-        }
+        OperatingSystem::Darwin
+        | OperatingSystem::Ios
+        | OperatingSystem::MacOSX { .. }
+        | OperatingSystem::Tvos
+        | OperatingSystem::Watchos => BinaryFormat::Macho,
         OperatingSystem::Windows => BinaryFormat::Coff,
+        OperatingSystem::Aix => BinaryFormat::Xcoff,
+        OperatingSystem::Zos => BinaryFormat::Goff,
         OperatingSystem::Nebulet
         | OperatingSystem::Emscripten
         | OperatingSystem::VxWorks
         | OperatingSystem::Wasi
+        | OperatingSystem::WasiP2
+        | OperatingSystem::WasiP3
         | OperatingSystem::Unknown => match triple.architecture {
             Architecture::Wasm32 | Architecture::Wasm64 => BinaryFormat::Wasm,
+            _ if matches!(triple.environment, Environment::Sgx) => BinaryFormat::Elf,
             _ => BinaryFormat::Unknown,
         },
         _ => BinaryFormat::Elf,
@@ -624,26 +1409,28 @@ impl fmt::Display for Aarch64Architecture {
     }
 }
 
-impl fmt::Display for Riscv32Architecture {
+impl fmt::Display for RiscvArchitecture {
+    /// Writes `G` in place of the extensions it stands for, so
+    /// `riscv64imafdc` is written as `riscv64gc`.
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let s = match *self {
-            Riscv32Architecture::Riscv32 => "riscv32",
-            Riscv32Architecture::Riscv32i => "riscv32i",
-            Riscv32Architecture::Riscv32imac => "riscv32imac",
-            Riscv32Architecture::Riscv32imc => "riscv32imc",
-        };
-        f.write_str(s)
+        write!(f, "riscv{}", self.base_width.bits())?;
+        let mut extensions = self.extensions;
+        if extensions & RISCV_G == RISCV_G {
+            f.write_str("g")?;
+            extensions &= !RISCV_G;
+        }
+        for extension in RISCV_EXTENSIONS.iter() {
+            if extensions & (1 << *extension as u16) != 0 {
+                write!(f, "{}", extension)?;
+            }
+        }
+        Ok(())
     }
 }
 
-impl fmt::Display for Riscv64Architecture {
+impl fmt::Display for RiscvExtension {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let s = match *self {
-            Riscv64Architecture::Riscv64 => "riscv64",
-            Riscv64Architecture::Riscv64gc => "riscv64gc",
-            Riscv64Architecture::Riscv64imac => "riscv64imac",
-        };
-        f.write_str(s)
+        write!(f, "{}", self.letter())
     }
 }
 
@@ -690,8 +1477,10 @@ impl fmt::Display for Architecture {
             Architecture::Unknown => f.write_str("unknown"),
             Architecture::AmdGcn => f.write_str("amdgcn"),
             Architecture::Asmjs => f.write_str("asmjs"),
+            Architecture::Dxil => f.write_str("dxil"),
             Architecture::Hexagon => f.write_str("hexagon"),
             Architecture::X86_32(x86_32) => x86_32.fmt(f),
+            Architecture::M68k => f.write_str("m68k"),
             Architecture::Mips32(mips32) => mips32.fmt(f),
             Architecture::Mips64(mips64) => mips64.fmt(f),
             Architecture::Msp430 => f.write_str("msp430"),
@@ -699,103 +1488,154 @@ impl fmt::Display for Architecture {
             Architecture::Powerpc => f.write_str("powerpc"),
             Architecture::Powerpc64 => f.write_str("powerpc64"),
             Architecture::Powerpc64le => f.write_str("powerpc64le"),
-            Architecture::Riscv32(riscv32) => riscv32.fmt(f),
-            Architecture::Riscv64(riscv64) => riscv64.fmt(f),
+            Architecture::Riscv(riscv) => riscv.fmt(f),
             Architecture::S390x => f.write_str("s390x"),
             Architecture::Sparc => f.write_str("sparc"),
             Architecture::Sparc64 => f.write_str("sparc64"),
             Architecture::Sparcv9 => f.write_str("sparcv9"),
+            Architecture::Spirv => f.write_str("spirv"),
+            Architecture::Spirv32 => f.write_str("spirv32"),
+            Architecture::Spirv64 => f.write_str("spirv64"),
             Architecture::Wasm32 => f.write_str("wasm32"),
             Architecture::Wasm64 => f.write_str("wasm64"),
             Architecture::X86_64 => f.write_str("x86_64"),
+            Architecture::Xtensa => f.write_str("xtensa"),
         }
     }
 }
 
-impl FromStr for ArmArchitecture {
-    type Err = ();
+// The `FromStr` impls below forward to `from_bytes` functions, which can be
+// called in `const` contexts, so that `Triple::from_str_const` shares their
+// tables instead of keeping a copy.
 
-    fn from_str(s: &str) -> Result<Self, ()> {
+impl ArmArchitecture {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
         Ok(match s {
-            "arm" => ArmArchitecture::Arm,
-            "armeb" => ArmArchitecture::Armeb,
-            "armv4" => ArmArchitecture::Armv4,
-            "armv4t" => ArmArchitecture::Armv4t,
-            "armv5t" => ArmArchitecture::Armv5t,
-            "armv5te" => ArmArchitecture::Armv5te,
-            "armv5tej" => ArmArchitecture::Armv5tej,
-            "armv6" => ArmArchitecture::Armv6,
-            "armv6j" => ArmArchitecture::Armv6j,
-            "armv6k" => ArmArchitecture::Armv6k,
-            "armv6z" => ArmArchitecture::Armv6z,
-            "armv6kz" => ArmArchitecture::Armv6kz,
-            "armv6t2" => ArmArchitecture::Armv6t2,
-            "armv6m" => ArmArchitecture::Armv6m,
-            "armv7" => ArmArchitecture::Armv7,
-            "armv7a" => ArmArchitecture::Armv7a,
-            "armv7ve" => ArmArchitecture::Armv7ve,
-            "armv7m" => ArmArchitecture::Armv7m,
-            "armv7r" => ArmArchitecture::Armv7r,
-            "armv7s" => ArmArchitecture::Armv7s,
-            "armv8" => ArmArchitecture::Armv8,
-            "armv8a" => ArmArchitecture::Armv8a,
-            "armv8.1a" => ArmArchitecture::Armv8_1a,
-            "armv8.2a" => ArmArchitecture::Armv8_2a,
-            "armv8.3a" => ArmArchitecture::Armv8_3a,
-            "armv8.4a" => ArmArchitecture::Armv8_4a,
-            "armv8.5a" => ArmArchitecture::Armv8_5a,
-            "armv8m.base" => ArmArchitecture::Armv8mBase,
-            "armv8m.main" => ArmArchitecture::Armv8mMain,
-            "armv8r" => ArmArchitecture::Armv8r,
-            "thumbeb" => ArmArchitecture::Thumbeb,
-            "thumbv6m" => ArmArchitecture::Thumbv6m,
-            "thumbv7a" => ArmArchitecture::Thumbv7a,
-            "thumbv7em" => ArmArchitecture::Thumbv7em,
-            "thumbv7m" => ArmArchitecture::Thumbv7m,
-            "thumbv7neon" => ArmArchitecture::Thumbv7neon,
-            "thumbv8m.base" => ArmArchitecture::Thumbv8mBase,
-            "thumbv8m.main" => ArmArchitecture::Thumbv8mMain,
-            "armebv7r" => ArmArchitecture::Armebv7r,
+            b"arm" => ArmArchitecture::Arm,
+            b"armeb" => ArmArchitecture::Armeb,
+            b"armv4" => ArmArchitecture::Armv4,
+            b"armv4t" => ArmArchitecture::Armv4t,
+            b"armv5t" => ArmArchitecture::Armv5t,
+            b"armv5te" => ArmArchitecture::Armv5te,
+            b"armv5tej" => ArmArchitecture::Armv5tej,
+            b"armv6" => ArmArchitecture::Armv6,
+            b"armv6j" => ArmArchitecture::Armv6j,
+            b"armv6k" => ArmArchitecture::Armv6k,
+            b"armv6z" => ArmArchitecture::Armv6z,
+            b"armv6kz" => ArmArchitecture::Armv6kz,
+            b"armv6t2" => ArmArchitecture::Armv6t2,
+            b"armv6m" => ArmArchitecture::Armv6m,
+            b"armv7" => ArmArchitecture::Armv7,
+            b"armv7a" => ArmArchitecture::Armv7a,
+            b"armv7ve" => ArmArchitecture::Armv7ve,
+            b"armv7m" => ArmArchitecture::Armv7m,
+            b"armv7r" => ArmArchitecture::Armv7r,
+            b"armv7s" => ArmArchitecture::Armv7s,
+            b"armv8" => ArmArchitecture::Armv8,
+            b"armv8a" => ArmArchitecture::Armv8a,
+            b"armv8.1a" => ArmArchitecture::Armv8_1a,
+            b"armv8.2a" => ArmArchitecture::Armv8_2a,
+            b"armv8.3a" => ArmArchitecture::Armv8_3a,
+            b"armv8.4a" => ArmArchitecture::Armv8_4a,
+            b"armv8.5a" => ArmArchitecture::Armv8_5a,
+            b"armv8m.base" => ArmArchitecture::Armv8mBase,
+            b"armv8m.main" => ArmArchitecture::Armv8mMain,
+            b"armv8r" => ArmArchitecture::Armv8r,
+            b"thumbeb" => ArmArchitecture::Thumbeb,
+            b"thumbv6m" => ArmArchitecture::Thumbv6m,
+            b"thumbv7a" => ArmArchitecture::Thumbv7a,
+            b"thumbv7em" => ArmArchitecture::Thumbv7em,
+            b"thumbv7m" => ArmArchitecture::Thumbv7m,
+            b"thumbv7neon" => ArmArchitecture::Thumbv7neon,
+            b"thumbv8m.base" => ArmArchitecture::Thumbv8mBase,
+            b"thumbv8m.main" => ArmArchitecture::Thumbv8mMain,
+            b"armebv7r" => ArmArchitecture::Armebv7r,
             _ => return Err(()),
         })
     }
 }
 
-impl FromStr for Aarch64Architecture {
+impl FromStr for ArmArchitecture {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl Aarch64Architecture {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
         Ok(match s {
-            "aarch64" => Aarch64Architecture::Aarch64,
-            "arm64" => Aarch64Architecture::Aarch64,
-            "aarch64be" => Aarch64Architecture::Aarch64be,
+            b"aarch64" => Aarch64Architecture::Aarch64,
+            b"arm64" => Aarch64Architecture::Aarch64,
+            b"aarch64be" => Aarch64Architecture::Aarch64be,
             _ => return Err(()),
         })
     }
 }
 
-impl FromStr for Riscv32Architecture {
+impl FromStr for Aarch64Architecture {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, ()> {
-        Ok(match s {
-            "riscv32" => Riscv32Architecture::Riscv32,
-            "riscv32i" => Riscv32Architecture::Riscv32i,
-            "riscv32imac" => Riscv32Architecture::Riscv32imac,
-            "riscv32imc" => Riscv32Architecture::Riscv32imc,
-            _ => return Err(()),
-        })
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl RiscvArchitecture {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
+        let (mut riscv, mut letters) = if let Some(letters) = strip_prefix(s, b"riscv32") {
+            (Self::riscv32(), letters)
+        } else if let Some(letters) = strip_prefix(s, b"riscv64") {
+            (Self::riscv64(), letters)
+        } else {
+            return Err(());
+        };
+
+        let mut next = 0;
+        if let Some(rest) = strip_prefix(letters, b"g") {
+            riscv.extensions = RISCV_G;
+            next = RiscvExtension::Q as usize;
+            letters = rest;
+        }
+        let mut i = 0;
+        while i < letters.len() {
+            let mut index = 0;
+            while index < RISCV_EXTENSIONS.len()
+                && RISCV_EXTENSIONS[index].letter() as u8 != letters[i]
+            {
+                index += 1;
+            }
+            if index == RISCV_EXTENSIONS.len() || index < next {
+                return Err(());
+            }
+            riscv = match riscv.checked_with_extension(RISCV_EXTENSIONS[index]) {
+                Some(riscv) => riscv,
+                None => return Err(()),
+            };
+            next = index + 1;
+            i += 1;
+        }
+        Ok(riscv)
     }
 }
 
-impl FromStr for Riscv64Architecture {
+impl FromStr for RiscvArchitecture {
     type Err = ();
 
+    /// Extensions must be written in canonical order, with `G`, if present,
+    /// first, and `I` and `E` are exclusive.
     fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl X86_32Architecture {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
         Ok(match s {
-            "riscv64" => Riscv64Architecture::Riscv64,
-            "riscv64gc" => Riscv64Architecture::Riscv64gc,
-            "riscv64imac" => Riscv64Architecture::Riscv64imac,
+            b"i386" => X86_32Architecture::I386,
+            b"i586" => X86_32Architecture::I586,
+            b"i686" => X86_32Architecture::I686,
             _ => return Err(()),
         })
     }
@@ -805,10 +1645,17 @@ impl FromStr for X86_32Architecture {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl Mips32Architecture {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
         Ok(match s {
-            "i386" => X86_32Architecture::I386,
-            "i586" => X86_32Architecture::I586,
-            "i686" => X86_32Architecture::I686,
+            b"mips" => Mips32Architecture::Mips,
+            b"mipsel" => Mips32Architecture::Mipsel,
+            b"mipsisa32r6" => Mips32Architecture::Mipsisa32r6,
+            b"mipsisa32r6el" => Mips32Architecture::Mipsisa32r6el,
             _ => return Err(()),
         })
     }
@@ -818,11 +1665,17 @@ impl FromStr for Mips32Architecture {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl Mips64Architecture {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
         Ok(match s {
-            "mips" => Mips32Architecture::Mips,
-            "mipsel" => Mips32Architecture::Mipsel,
-            "mipsisa32r6" => Mips32Architecture::Mipsisa32r6,
-            "mipsisa32r6el" => Mips32Architecture::Mipsisa32r6el,
+            b"mips64" => Mips64Architecture::Mips64,
+            b"mips64el" => Mips64Architecture::Mips64el,
+            b"mipsisa64r6" => Mips64Architecture::Mipsisa64r6,
+            b"mipsisa64r6el" => Mips64Architecture::Mipsisa64r6el,
             _ => return Err(()),
         })
     }
@@ -832,51 +1685,47 @@ impl FromStr for Mips64Architecture {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, ()> {
-        Ok(match s {
-            "mips64" => Mips64Architecture::Mips64,
-            "mips64el" => Mips64Architecture::Mips64el,
-            "mipsisa64r6" => Mips64Architecture::Mipsisa64r6,
-            "mipsisa64r6el" => Mips64Architecture::Mipsisa64r6el,
-            _ => return Err(()),
-        })
+        Self::from_bytes(s.as_bytes())
     }
 }
 
-impl FromStr for Architecture {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, ()> {
+impl Architecture {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
         Ok(match s {
-            "unknown" => Architecture::Unknown,
-            "amdgcn" => Architecture::AmdGcn,
-            "asmjs" => Architecture::Asmjs,
-            "hexagon" => Architecture::Hexagon,
-            "msp430" => Architecture::Msp430,
-            "nvptx64" => Architecture::Nvptx64,
-            "powerpc" => Architecture::Powerpc,
-            "powerpc64" => Architecture::Powerpc64,
-            "powerpc64le" => Architecture::Powerpc64le,
-            "s390x" => Architecture::S390x,
-            "sparc" => Architecture::Sparc,
-            "sparc64" => Architecture::Sparc64,
-            "sparcv9" => Architecture::Sparcv9,
-            "wasm32" => Architecture::Wasm32,
-            "wasm64" => Architecture::Wasm64,
-            "x86_64" => Architecture::X86_64,
+            b"unknown" => Architecture::Unknown,
+            b"amdgcn" => Architecture::AmdGcn,
+            b"asmjs" => Architecture::Asmjs,
+            b"dxil" => Architecture::Dxil,
+            b"hexagon" => Architecture::Hexagon,
+            b"m68k" => Architecture::M68k,
+            b"msp430" => Architecture::Msp430,
+            b"nvptx64" => Architecture::Nvptx64,
+            b"powerpc" => Architecture::Powerpc,
+            b"powerpc64" => Architecture::Powerpc64,
+            b"powerpc64le" => Architecture::Powerpc64le,
+            b"s390x" => Architecture::S390x,
+            b"sparc" => Architecture::Sparc,
+            b"sparc64" => Architecture::Sparc64,
+            b"sparcv9" => Architecture::Sparcv9,
+            b"spirv" => Architecture::Spirv,
+            b"spirv32" => Architecture::Spirv32,
+            b"spirv64" => Architecture::Spirv64,
+            b"wasm32" => Architecture::Wasm32,
+            b"wasm64" => Architecture::Wasm64,
+            b"x86_64" => Architecture::X86_64,
+            b"xtensa" => Architecture::Xtensa,
             _ => {
-                if let Ok(arm) = ArmArchitecture::from_str(s) {
+                if let Ok(arm) = ArmArchitecture::from_bytes(s) {
                     Architecture::Arm(arm)
-                } else if let Ok(aarch64) = Aarch64Architecture::from_str(s) {
+                } else if let Ok(aarch64) = Aarch64Architecture::from_bytes(s) {
                     Architecture::Aarch64(aarch64)
-                } else if let Ok(riscv32) = Riscv32Architecture::from_str(s) {
-                    Architecture::Riscv32(riscv32)
-                } else if let Ok(riscv64) = Riscv64Architecture::from_str(s) {
-                    Architecture::Riscv64(riscv64)
-                } else if let Ok(x86_32) = X86_32Architecture::from_str(s) {
+                } else if let Ok(riscv) = RiscvArchitecture::from_bytes(s) {
+                    Architecture::Riscv(riscv)
+                } else if let Ok(x86_32) = X86_32Architecture::from_bytes(s) {
                     Architecture::X86_32(x86_32)
-                } else if let Ok(mips32) = Mips32Architecture::from_str(s) {
+                } else if let Ok(mips32) = Mips32Architecture::from_bytes(s) {
                     Architecture::Mips32(mips32)
-                } else if let Ok(mips64) = Mips64Architecture::from_str(s) {
+                } else if let Ok(mips64) = Mips64Architecture::from_bytes(s) {
                     Architecture::Mips64(mips64)
                 } else {
                     return Err(());
@@ -886,80 +1735,164 @@ impl FromStr for Architecture {
     }
 }
 
-impl fmt::Display for Vendor {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let s = match *self {
+impl FromStr for Architecture {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl Vendor {
+    /// Extracts a string slice.
+    pub fn as_str(&self) -> &str {
+        match *self {
             Vendor::Unknown => "unknown",
             Vendor::Amd => "amd",
             Vendor::Apple => "apple",
+            Vendor::Espressif(EspressifVendor::Esp) => "esp",
+            Vendor::Espressif(EspressifVendor::Esp32) => "esp32",
+            Vendor::Espressif(EspressifVendor::Esp32s2) => "esp32s2",
+            Vendor::Espressif(EspressifVendor::Esp32s3) => "esp32s3",
             Vendor::Experimental => "experimental",
             Vendor::Fortanix => "fortanix",
+            Vendor::Ibm => "ibm",
+            Vendor::Kmc => "kmc",
+            Vendor::Mti => "mti",
+            Vendor::Nintendo => "nintendo",
             Vendor::Nvidia => "nvidia",
+            Vendor::OpenWrt => "openwrt",
             Vendor::Pc => "pc",
             Vendor::Rumprun => "rumprun",
+            Vendor::Sony => "sony",
             Vendor::Sun => "sun",
+            Vendor::Unikraft => "unikraft",
             Vendor::Uwp => "uwp",
+            Vendor::Win7 => "win7",
             Vendor::Wrs => "wrs",
             Vendor::Custom(ref name) => name.as_str(),
-        };
-        f.write_str(s)
+        }
     }
 }
 
-impl FromStr for Vendor {
-    type Err = ();
+impl fmt::Display for Vendor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
-    fn from_str(s: &str) -> Result<Self, ()> {
-        Ok(match s {
-            "unknown" => Vendor::Unknown,
-            "amd" => Vendor::Amd,
-            "apple" => Vendor::Apple,
-            "experimental" => Vendor::Experimental,
-            "fortanix" => Vendor::Fortanix,
-            "nvidia" => Vendor::Nvidia,
-            "pc" => Vendor::Pc,
-            "rumprun" => Vendor::Rumprun,
-            "sun" => Vendor::Sun,
-            "uwp" => Vendor::Uwp,
-            "wrs" => Vendor::Wrs,
-            custom => {
-                use alloc::borrow::ToOwned;
-
-                // A custom vendor. Since triple syntax is so loosely defined,
-                // be as conservative as we can to avoid potential ambiguities.
-                // We err on the side of being too strict here, as we can
-                // always relax it if needed.
-
-                // Don't allow empty string names.
-                if custom.is_empty() {
-                    return Err(());
-                }
+impl Vendor {
+    /// Unlike the other `from_bytes` functions, this only recognizes the
+    /// named vendors, and returns an `Option`, as `Option::unwrap` can be
+    /// used in `const` contexts.
+    pub(crate) const fn from_bytes(s: &[u8]) -> Option<Self> {
+        Some(match s {
+            b"unknown" => Vendor::Unknown,
+            b"amd" => Vendor::Amd,
+            b"apple" => Vendor::Apple,
+            b"esp" | b"espressif" => Vendor::Espressif(EspressifVendor::Esp),
+            b"esp32" => Vendor::Espressif(EspressifVendor::Esp32),
+            b"esp32s2" => Vendor::Espressif(EspressifVendor::Esp32s2),
+            b"esp32s3" => Vendor::Espressif(EspressifVendor::Esp32s3),
+            b"experimental" => Vendor::Experimental,
+            b"fortanix" => Vendor::Fortanix,
+            b"ibm" => Vendor::Ibm,
+            b"kmc" => Vendor::Kmc,
+            b"mti" => Vendor::Mti,
+            b"nintendo" => Vendor::Nintendo,
+            b"nvidia" => Vendor::Nvidia,
+            b"openwrt" => Vendor::OpenWrt,
+            b"pc" => Vendor::Pc,
+            b"rumprun" => Vendor::Rumprun,
+            b"sony" => Vendor::Sony,
+            b"sie" => Vendor::Sony,
+            b"scei" => Vendor::Sony,
+            b"sun" => Vendor::Sun,
+            b"unikraft" => Vendor::Unikraft,
+            b"uwp" => Vendor::Uwp,
+            b"win7" => Vendor::Win7,
+            b"wrs" => Vendor::Wrs,
+            _ => return None,
+        })
+    }
 
-                // Don't allow any other recognized name as a custom vendor,
-                // since vendors can be omitted in some contexts.
-                if Architecture::from_str(custom).is_ok()
-                    || OperatingSystem::from_str(custom).is_ok()
-                    || Environment::from_str(custom).is_ok()
-                    || BinaryFormat::from_str(custom).is_ok()
-                {
-                    return Err(());
-                }
+    /// Test whether `s` is acceptable as the name of a custom vendor.
+    pub(crate) const fn is_custom(s: &[u8]) -> bool {
+        // A custom vendor. Since triple syntax is so loosely defined,
+        // be as conservative as we can to avoid potential ambiguities.
+        // We err on the side of being too strict here, as we can
+        // always relax it if needed.
 
-                // Require the first character to be an ascii lowercase.
-                if !custom.chars().next().unwrap().is_ascii_lowercase() {
-                    return Err(());
-                }
+        // Don't allow empty string names.
+        if s.is_empty() {
+            return false;
+        }
 
-                // Restrict the set of characters permitted in a custom vendor.
-                if custom.chars().any(|c: char| {
-                    !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.')
-                }) {
-                    return Err(());
-                }
+        // Don't allow any other recognized name as a custom vendor,
+        // since vendors can be omitted in some contexts.
+        if Architecture::from_bytes(s).is_ok()
+            || OperatingSystem::from_bytes(s).is_ok()
+            || Environment::from_bytes(s).is_ok()
+            || BinaryFormat::from_bytes(s).is_ok()
+        {
+            return false;
+        }
+
+        // Require the first character to be an ascii lowercase.
+        if !s[0].is_ascii_lowercase() {
+            return false;
+        }
 
-                Vendor::Custom(CustomVendor::Owned(Box::new(custom.to_owned())))
+        // Restrict the set of characters permitted in a custom vendor.
+        let mut i = 0;
+        while i < s.len() {
+            let c = s[i];
+            if !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == b'_' || c == b'.') {
+                return false;
             }
-        })
+            i += 1;
+        }
+        true
+    }
+
+    /// Test whether `s` names a vendor, either a named one or a custom one.
+    pub(crate) const fn is_valid(s: &[u8]) -> bool {
+        let named = Self::from_bytes(s);
+        let is_named = named.is_some();
+        // `named` has nothing to drop, but the `const` checker can't tell.
+        mem::forget(named);
+        is_named || Self::is_custom(s)
+    }
+
+    /// Parse a vendor which `is_valid`, in a `const` context, storing a
+    /// custom vendor as a `CustomVendor::Static`.
+    #[allow(clippy::unnecessary_unwrap)]
+    pub(crate) const fn from_static_str(s: &'static str) -> Self {
+        // Matching on `named` would leave it to be dropped, which the `const`
+        // checker rejects, so test and unwrap it instead.
+        let named = Self::from_bytes(s.as_bytes());
+        if named.is_some() {
+            named.unwrap()
+        } else {
+            mem::forget(named);
+            Vendor::Custom(CustomVendor::Static(s))
+        }
+    }
+}
+
+impl FromStr for Vendor {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        use alloc::borrow::ToOwned;
+
+        if let Some(vendor) = Self::from_bytes(s.as_bytes()) {
+            Ok(vendor)
+        } else if Self::is_custom(s.as_bytes()) {
+            Ok(Vendor::Custom(CustomVendor::Owned(Box::new(s.to_owned()))))
+        } else {
+            Err(())
+        }
     }
 }
 
@@ -967,13 +1900,16 @@ impl fmt::Display for OperatingSystem {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let s = match *self {
             OperatingSystem::Unknown => "unknown",
+            OperatingSystem::Aix => "aix",
             OperatingSystem::AmdHsa => "amdhsa",
+            OperatingSystem::AmdPal => "amdpal",
             OperatingSystem::Bitrig => "bitrig",
             OperatingSystem::Cloudabi => "cloudabi",
             OperatingSystem::Cuda => "cuda",
             OperatingSystem::Darwin => "darwin",
             OperatingSystem::Dragonfly => "dragonfly",
             OperatingSystem::Emscripten => "emscripten",
+            OperatingSystem::Espidf => "espidf",
             OperatingSystem::Freebsd => "freebsd",
             OperatingSystem::Fuchsia => "fuchsia",
             OperatingSystem::Haiku => "haiku",
@@ -988,87 +1924,132 @@ impl fmt::Display for OperatingSystem {
             } => {
                 return write!(f, "macosx{}.{}.{}", major, minor, patch);
             }
+            OperatingSystem::Managarm => "managarm",
             OperatingSystem::Nebulet => "nebulet",
             OperatingSystem::Netbsd => "netbsd",
+            OperatingSystem::Nintendo3ds => "3ds",
             OperatingSystem::None_ => "none",
+            OperatingSystem::Nuttx => "nuttx",
             OperatingSystem::Openbsd => "openbsd",
+            OperatingSystem::Ps4 => "ps4",
+            OperatingSystem::Ps5 => "ps5",
+            OperatingSystem::Psp => "psp",
+            OperatingSystem::Psx => "psx",
             OperatingSystem::Redox => "redox",
+            OperatingSystem::Rtems => "rtems",
+            OperatingSystem::ShaderModel { major, minor } => {
+                return write!(f, "shadermodel{}.{}", major, minor);
+            }
             OperatingSystem::Solaris => "solaris",
+            OperatingSystem::SolidAsp3 => "solid_asp3",
+            OperatingSystem::Switch => "switch",
+            OperatingSystem::Tvos => "tvos",
             OperatingSystem::Uefi => "uefi",
+            OperatingSystem::Vita => "vita",
             OperatingSystem::VxWorks => "vxworks",
             OperatingSystem::Wasi => "wasi",
+            OperatingSystem::WasiP2 => "wasip2",
+            OperatingSystem::WasiP3 => "wasip3",
+            OperatingSystem::Watchos => "watchos",
             OperatingSystem::Windows => "windows",
+            OperatingSystem::Zos => "zos",
         };
         f.write_str(s)
     }
 }
 
-impl FromStr for OperatingSystem {
-    type Err = ();
-
-    fn from_str(s: &str) -> Result<Self, ()> {
+impl OperatingSystem {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
         // TODO also parse version number for darwin and ios OSes
-        if s.starts_with("macosx") {
+        if let Some(s) = strip_prefix(s, b"macosx") {
             // Parse operating system names like `macosx10.7.0`.
-            let s = &s["macosx".len()..];
-            let mut parts = s.split('.').map(|num| num.parse::<u16>());
-
-            macro_rules! get_part {
-                () => {
-                    if let Some(Ok(part)) = parts.next() {
-                        part
-                    } else {
-                        return Err(());
-                    }
-                };
-            }
-
-            let major = get_part!();
-            let minor = get_part!();
-            let patch = get_part!();
-
-            if parts.next().is_some() {
-                return Err(());
-            }
-
-            return Ok(OperatingSystem::MacOSX {
-                major,
-                minor,
-                patch,
-            });
+            let (major, rest) = match split_once(s, b'.') {
+                Some(parts) => parts,
+                None => return Err(()),
+            };
+            let (minor, patch) = match split_once(rest, b'.') {
+                Some(parts) => parts,
+                None => return Err(()),
+            };
+            return match (parse_u16(major), parse_u16(minor), parse_u16(patch)) {
+                (Ok(major), Ok(minor), Ok(patch)) => Ok(OperatingSystem::MacOSX {
+                    major,
+                    minor,
+                    patch,
+                }),
+                _ => Err(()),
+            };
+        }
+        if let Some(s) = strip_prefix(s, b"shadermodel") {
+            // Parse operating system names like `shadermodel6.3`.
+            let (major, minor) = match split_once(s, b'.') {
+                Some(parts) => parts,
+                None => return Err(()),
+            };
+            return match (parse_u16(major), parse_u16(minor)) {
+                (Ok(major), Ok(minor)) => Ok(OperatingSystem::ShaderModel { major, minor }),
+                _ => Err(()),
+            };
         }
 
         Ok(match s {
-            "unknown" => OperatingSystem::Unknown,
-            "amdhsa" => OperatingSystem::AmdHsa,
-            "bitrig" => OperatingSystem::Bitrig,
-            "cloudabi" => OperatingSystem::Cloudabi,
-            "cuda" => OperatingSystem::Cuda,
-            "darwin" => OperatingSystem::Darwin,
-            "dragonfly" => OperatingSystem::Dragonfly,
-            "emscripten" => OperatingSystem::Emscripten,
-            "freebsd" => OperatingSystem::Freebsd,
-            "fuchsia" => OperatingSystem::Fuchsia,
-            "haiku" => OperatingSystem::Haiku,
-            "hermit" => OperatingSystem::Hermit,
-            "ios" => OperatingSystem::Ios,
-            "l4re" => OperatingSystem::L4re,
-            "linux" => OperatingSystem::Linux,
-            "nebulet" => OperatingSystem::Nebulet,
-            "netbsd" => OperatingSystem::Netbsd,
-            "none" => OperatingSystem::None_,
-            "openbsd" => OperatingSystem::Openbsd,
-            "redox" => OperatingSystem::Redox,
-            "solaris" => OperatingSystem::Solaris,
-            "uefi" => OperatingSystem::Uefi,
-            "vxworks" => OperatingSystem::VxWorks,
-            "wasi" => OperatingSystem::Wasi,
-            "windows" => OperatingSystem::Windows,
+            b"unknown" => OperatingSystem::Unknown,
+            b"aix" => OperatingSystem::Aix,
+            b"amdhsa" => OperatingSystem::AmdHsa,
+            b"amdpal" => OperatingSystem::AmdPal,
+            b"bitrig" => OperatingSystem::Bitrig,
+            b"cloudabi" => OperatingSystem::Cloudabi,
+            b"cuda" => OperatingSystem::Cuda,
+            b"darwin" => OperatingSystem::Darwin,
+            b"dragonfly" => OperatingSystem::Dragonfly,
+            b"emscripten" => OperatingSystem::Emscripten,
+            b"espidf" => OperatingSystem::Espidf,
+            b"freebsd" => OperatingSystem::Freebsd,
+            b"fuchsia" => OperatingSystem::Fuchsia,
+            b"haiku" => OperatingSystem::Haiku,
+            b"hermit" => OperatingSystem::Hermit,
+            b"ios" => OperatingSystem::Ios,
+            b"l4re" => OperatingSystem::L4re,
+            b"linux" => OperatingSystem::Linux,
+            b"managarm" => OperatingSystem::Managarm,
+            b"nebulet" => OperatingSystem::Nebulet,
+            b"netbsd" => OperatingSystem::Netbsd,
+            b"3ds" => OperatingSystem::Nintendo3ds,
+            b"none" => OperatingSystem::None_,
+            b"nuttx" => OperatingSystem::Nuttx,
+            b"openbsd" => OperatingSystem::Openbsd,
+            b"ps4" => OperatingSystem::Ps4,
+            b"ps5" => OperatingSystem::Ps5,
+            b"psp" => OperatingSystem::Psp,
+            b"psx" => OperatingSystem::Psx,
+            b"redox" => OperatingSystem::Redox,
+            b"rtems" => OperatingSystem::Rtems,
+            b"solaris" => OperatingSystem::Solaris,
+            b"solid_asp3" => OperatingSystem::SolidAsp3,
+            b"switch" => OperatingSystem::Switch,
+            b"tvos" => OperatingSystem::Tvos,
+            b"uefi" => OperatingSystem::Uefi,
+            b"vita" => OperatingSystem::Vita,
+            b"vxworks" => OperatingSystem::VxWorks,
+            b"wasi" => OperatingSystem::Wasi,
+            b"wasip2" => OperatingSystem::WasiP2,
+            b"wasip3" => OperatingSystem::WasiP3,
+            b"watchos" => OperatingSystem::Watchos,
+            b"windows" => OperatingSystem::Windows,
+            b"zos" => OperatingSystem::Zos,
             _ => return Err(()),
         })
     }
 }
 
+impl FromStr for OperatingSystem {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
 impl fmt::Display for Environment {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let s = match *self {
@@ -1079,58 +2060,244 @@ impl fmt::Display for Environment {
             Environment::Eabi => "eabi",
             Environment::Eabihf => "eabihf",
             Environment::Gnu => "gnu",
+            Environment::Gnullvm => "gnullvm",
+            Environment::GnuUcrt => "gnu.ucrt",
+            Environment::GnuMsvcrt => "gnu.msvcrt",
             Environment::Gnuabi64 => "gnuabi64",
             Environment::Gnueabi => "gnueabi",
             Environment::Gnueabihf => "gnueabihf",
+            Environment::GnuIlp32 => "gnu_ilp32",
             Environment::Gnuspe => "gnuspe",
             Environment::Gnux32 => "gnux32",
             Environment::Musl => "musl",
             Environment::Musleabi => "musleabi",
             Environment::Musleabihf => "musleabihf",
             Environment::Muslabi64 => "muslabi64",
+            Environment::Relibc => "relibc",
+            Environment::Mlibc => "mlibc",
             Environment::Msvc => "msvc",
+            Environment::MsvcVersioned(version) => return write!(f, "msvc.{}", version),
             Environment::Kernel => "kernel",
+            Environment::Freestanding => "freestanding",
+            Environment::MacABI => "macabi",
+            Environment::Sim => "sim",
+            Environment::Newlib => "newlib",
+            Environment::Newlibeabi => "newlibeabi",
+            Environment::Newlibeabihf => "newlibeabihf",
             Environment::Uclibc => "uclibc",
             Environment::Sgx => "sgx",
             Environment::Softfloat => "softfloat",
             Environment::Spe => "spe",
+            Environment::Pixel => "pixel",
+            Environment::Vertex => "vertex",
+            Environment::Geometry => "geometry",
+            Environment::Hull => "hull",
+            Environment::Domain => "domain",
+            Environment::Compute => "compute",
+            Environment::Library => "library",
+            Environment::RayGeneration => "raygeneration",
+            Environment::Intersection => "intersection",
+            Environment::AnyHit => "anyhit",
+            Environment::ClosestHit => "closesthit",
+            Environment::Miss => "miss",
+            Environment::Callable => "callable",
+            Environment::Mesh => "mesh",
+            Environment::Amplification => "amplification",
         };
         f.write_str(s)
     }
 }
 
+impl Environment {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
+        if let Some(s) = strip_prefix(s, b"msvc.") {
+            // Parse environment names like `msvc.vc143`, `msvc.mt`, or
+            // `msvc.vc143.mt`.
+            let (first, rest) = match split_once(s, b'.') {
+                Some((first, rest)) => (first, Some(rest)),
+                None => (s, None),
+            };
+            let (toolset, part, rest) = match MsvcToolset::from_bytes(first) {
+                Ok(toolset) => match rest {
+                    Some(rest) => match split_once(rest, b'.') {
+                        Some((part, rest)) => (Some(toolset), Some(part), Some(rest)),
+                        None => (Some(toolset), Some(rest), None),
+                    },
+                    None => (Some(toolset), None, None),
+                },
+                Err(()) => (None, Some(first), rest),
+            };
+            let crt = match part {
+                Some(s) => match MsvcCrt::from_bytes(s) {
+                    Ok(crt) => Some(crt),
+                    Err(()) => return Err(()),
+                },
+                None => None,
+            };
+            if rest.is_some() {
+                return Err(());
+            }
+            return match (toolset, crt) {
+                (None, None) => Err(()),
+                _ => Ok(Environment::msvc(toolset, crt)),
+            };
+        }
+
+        Ok(match s {
+            b"unknown" => Environment::Unknown,
+            b"amdgiz" => Environment::AmdGiz,
+            b"android" => Environment::Android,
+            b"androideabi" => Environment::Androideabi,
+            b"eabi" => Environment::Eabi,
+            b"eabihf" => Environment::Eabihf,
+            b"gnu" => Environment::Gnu,
+            b"gnullvm" => Environment::Gnullvm,
+            b"gnu.ucrt" => Environment::GnuUcrt,
+            b"gnu.msvcrt" => Environment::GnuMsvcrt,
+            b"gnuabi64" => Environment::Gnuabi64,
+            b"gnueabi" => Environment::Gnueabi,
+            b"gnueabihf" => Environment::Gnueabihf,
+            b"gnu_ilp32" => Environment::GnuIlp32,
+            b"gnuspe" => Environment::Gnuspe,
+            b"gnux32" => Environment::Gnux32,
+            b"musl" => Environment::Musl,
+            b"musleabi" => Environment::Musleabi,
+            b"musleabihf" => Environment::Musleabihf,
+            b"muslabi64" => Environment::Muslabi64,
+            b"relibc" => Environment::Relibc,
+            b"mlibc" => Environment::Mlibc,
+            b"msvc" => Environment::Msvc,
+            b"kernel" => Environment::Kernel,
+            b"freestanding" => Environment::Freestanding,
+            b"macabi" => Environment::MacABI,
+            b"sim" => Environment::Sim,
+            b"newlib" => Environment::Newlib,
+            b"newlibeabi" => Environment::Newlibeabi,
+            b"newlibeabihf" => Environment::Newlibeabihf,
+            b"uclibc" => Environment::Uclibc,
+            b"sgx" => Environment::Sgx,
+            b"softfloat" => Environment::Softfloat,
+            b"spe" => Environment::Spe,
+            b"pixel" => Environment::Pixel,
+            b"vertex" => Environment::Vertex,
+            b"geometry" => Environment::Geometry,
+            b"hull" => Environment::Hull,
+            b"domain" => Environment::Domain,
+            b"compute" => Environment::Compute,
+            b"library" => Environment::Library,
+            b"raygeneration" => Environment::RayGeneration,
+            b"intersection" => Environment::Intersection,
+            b"anyhit" => Environment::AnyHit,
+            b"closesthit" => Environment::ClosestHit,
+            b"miss" => Environment::Miss,
+            b"callable" => Environment::Callable,
+            b"mesh" => Environment::Mesh,
+            b"amplification" => Environment::Amplification,
+            _ => return Err(()),
+        })
+    }
+}
+
 impl FromStr for Environment {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl fmt::Display for MsvcToolset {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            MsvcToolset::Vc140 => "vc140",
+            MsvcToolset::Vc141 => "vc141",
+            MsvcToolset::Vc142 => "vc142",
+            MsvcToolset::Vc143 => "vc143",
+        };
+        f.write_str(s)
+    }
+}
+
+impl MsvcToolset {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
+        Ok(match s {
+            b"vc140" => MsvcToolset::Vc140,
+            b"vc141" => MsvcToolset::Vc141,
+            b"vc142" => MsvcToolset::Vc142,
+            b"vc143" => MsvcToolset::Vc143,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl FromStr for MsvcToolset {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl MsvcVersion {
+    /// Return the MSVC toolset, if one is specified.
+    pub fn toolset(self) -> Option<MsvcToolset> {
+        match self {
+            MsvcVersion::Toolset(toolset) | MsvcVersion::ToolsetAndCrt(toolset, _) => Some(toolset),
+            MsvcVersion::Crt(_) => None,
+        }
+    }
+
+    /// Return how the C runtime is linked, if that is specified.
+    pub fn crt(self) -> Option<MsvcCrt> {
+        match self {
+            MsvcVersion::Crt(crt) | MsvcVersion::ToolsetAndCrt(_, crt) => Some(crt),
+            MsvcVersion::Toolset(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for MsvcVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            MsvcVersion::Toolset(toolset) => write!(f, "{}", toolset),
+            MsvcVersion::Crt(crt) => write!(f, "{}", crt),
+            MsvcVersion::ToolsetAndCrt(toolset, crt) => write!(f, "{}.{}", toolset, crt),
+        }
+    }
+}
+
+impl fmt::Display for MsvcCrt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            MsvcCrt::Dynamic => "md",
+            MsvcCrt::DynamicDebug => "mdd",
+            MsvcCrt::Static => "mt",
+            MsvcCrt::StaticDebug => "mtd",
+        };
+        f.write_str(s)
+    }
+}
+
+impl MsvcCrt {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
         Ok(match s {
-            "unknown" => Environment::Unknown,
-            "amdgiz" => Environment::AmdGiz,
-            "android" => Environment::Android,
-            "androideabi" => Environment::Androideabi,
-            "eabi" => Environment::Eabi,
-            "eabihf" => Environment::Eabihf,
-            "gnu" => Environment::Gnu,
-            "gnuabi64" => Environment::Gnuabi64,
-            "gnueabi" => Environment::Gnueabi,
-            "gnueabihf" => Environment::Gnueabihf,
-            "gnuspe" => Environment::Gnuspe,
-            "gnux32" => Environment::Gnux32,
-            "musl" => Environment::Musl,
-            "musleabi" => Environment::Musleabi,
-            "musleabihf" => Environment::Musleabihf,
-            "muslabi64" => Environment::Muslabi64,
-            "msvc" => Environment::Msvc,
-            "kernel" => Environment::Kernel,
-            "uclibc" => Environment::Uclibc,
-            "sgx" => Environment::Sgx,
-            "softfloat" => Environment::Softfloat,
-            "spe" => Environment::Spe,
+            b"md" => MsvcCrt::Dynamic,
+            b"mdd" => MsvcCrt::DynamicDebug,
+            b"mt" => MsvcCrt::Static,
+            b"mtd" => MsvcCrt::StaticDebug,
             _ => return Err(()),
         })
     }
 }
 
+impl FromStr for MsvcCrt {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
 impl fmt::Display for BinaryFormat {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let s = match *self {
@@ -1139,21 +2306,81 @@ impl fmt::Display for BinaryFormat {
             BinaryFormat::Coff => "coff",
             BinaryFormat::Macho => "macho",
             BinaryFormat::Wasm => "wasm",
+            BinaryFormat::Xcoff => "xcoff",
+            BinaryFormat::Goff => "goff",
+            BinaryFormat::Spirv => "spirv",
+            BinaryFormat::DxContainer => "dxcontainer",
+            BinaryFormat::Raw => "raw",
+            BinaryFormat::Ihex => "ihex",
         };
         f.write_str(s)
     }
 }
 
+impl BinaryFormat {
+    pub(crate) const fn from_bytes(s: &[u8]) -> Result<Self, ()> {
+        Ok(match s {
+            b"unknown" => BinaryFormat::Unknown,
+            b"elf" => BinaryFormat::Elf,
+            b"coff" => BinaryFormat::Coff,
+            b"macho" => BinaryFormat::Macho,
+            b"wasm" => BinaryFormat::Wasm,
+            b"xcoff" => BinaryFormat::Xcoff,
+            b"goff" => BinaryFormat::Goff,
+            b"spirv" => BinaryFormat::Spirv,
+            b"dxcontainer" => BinaryFormat::DxContainer,
+            b"raw" => BinaryFormat::Raw,
+            b"ihex" => BinaryFormat::Ihex,
+            _ => return Err(()),
+        })
+    }
+}
+
 impl FromStr for BinaryFormat {
     type Err = ();
 
+    fn from_str(s: &str) -> Result<Self, ()> {
+        Self::from_bytes(s.as_bytes())
+    }
+}
+
+impl fmt::Display for Abi {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            Abi::Abi64 => "abi64",
+            Abi::Eabi => "eabi",
+            Abi::Eabihf => "eabihf",
+            Abi::Fortanix => "fortanix",
+            Abi::Ilp32 => "ilp32",
+            Abi::Llvm => "llvm",
+            Abi::MacABI => "macabi",
+            Abi::Sim => "sim",
+            Abi::Softfloat => "softfloat",
+            Abi::Spe => "spe",
+            Abi::Uwp => "uwp",
+            Abi::X32 => "x32",
+        };
+        f.write_str(s)
+    }
+}
+
+impl FromStr for Abi {
+    type Err = ();
+
     fn from_str(s: &str) -> Result<Self, ()> {
         Ok(match s {
-            "unknown" => BinaryFormat::Unknown,
-            "elf" => BinaryFormat::Elf,
-            "coff" => BinaryFormat::Coff,
-            "macho" => BinaryFormat::Macho,
-            "wasm" => BinaryFormat::Wasm,
+            "abi64" => Abi::Abi64,
+            "eabi" => Abi::Eabi,
+            "eabihf" => Abi::Eabihf,
+            "fortanix" => Abi::Fortanix,
+            "ilp32" => Abi::Ilp32,
+            "llvm" => Abi::Llvm,
+            "macabi" => Abi::MacABI,
+            "sim" => Abi::Sim,
+            "softfloat" => Abi::Softfloat,
+            "spe" => Abi::Spe,
+            "uwp" => Abi::Uwp,
+            "x32" => Abi::X32,
             _ => return Err(()),
         })
     }
@@ -1166,172 +2393,10 @@ mod tests {
 
     #[test]
     fn roundtrip_known_triples() {
-        // This list is constructed from:
-        //  - targets emitted by "rustup target list"
-        //  - targets emitted by "rustc +nightly --print target-list"
-        //  - targets contributors have added
-        let targets = [
-            "aarch64-apple-ios",
-            "aarch64-fuchsia",
-            "aarch64-linux-android",
-            "aarch64-pc-windows-msvc",
-            "aarch64-unknown-cloudabi",
-            "aarch64-unknown-freebsd",
-            "aarch64-unknown-hermit",
-            "aarch64-unknown-linux-gnu",
-            "aarch64-unknown-linux-musl",
-            "aarch64-unknown-netbsd",
-            "aarch64-unknown-none",
-            "aarch64-unknown-none-softfloat",
-            "aarch64-unknown-openbsd",
-            "aarch64-unknown-redox",
-            "aarch64-uwp-windows-msvc",
-            "aarch64-wrs-vxworks",
-            "amdgcn-amd-amdhsa",
-            "amdgcn-amd-amdhsa-amdgiz",
-            "armebv7r-none-eabi",
-            "armebv7r-none-eabihf",
-            "arm-linux-androideabi",
-            "arm-unknown-linux-gnueabi",
-            "arm-unknown-linux-gnueabihf",
-            "arm-unknown-linux-musleabi",
-            "arm-unknown-linux-musleabihf",
-            "armv4t-unknown-linux-gnueabi",
-            "armv5te-unknown-linux-gnueabi",
-            "armv5te-unknown-linux-musleabi",
-            "armv6-unknown-freebsd",
-            "armv6-unknown-netbsd-eabihf",
-            "armv7a-none-eabi",
-            "armv7a-none-eabihf",
-            "armv7-apple-ios",
-            "armv7-linux-androideabi",
-            "armv7r-none-eabi",
-            "armv7r-none-eabihf",
-            "armv7s-apple-ios",
-            "armv7-unknown-cloudabi-eabihf",
-            "armv7-unknown-freebsd",
-            "armv7-unknown-linux-gnueabi",
-            "armv7-unknown-linux-gnueabihf",
-            "armv7-unknown-linux-musleabi",
-            "armv7-unknown-linux-musleabihf",
-            "armv7-unknown-netbsd-eabihf",
-            "armv7-wrs-vxworks-eabihf",
-            "asmjs-unknown-emscripten",
-            "hexagon-unknown-linux-musl",
-            "i386-apple-ios",
-            "i586-pc-windows-msvc",
-            "i586-unknown-linux-gnu",
-            "i586-unknown-linux-musl",
-            "i686-apple-darwin",
-            "i686-linux-android",
-            "i686-apple-macosx10.7.0",
-            "i686-pc-windows-gnu",
-            "i686-pc-windows-msvc",
-            "i686-unknown-cloudabi",
-            "i686-unknown-dragonfly",
-            "i686-unknown-freebsd",
-            "i686-unknown-haiku",
-            "i686-unknown-linux-gnu",
-            "i686-unknown-linux-musl",
-            "i686-unknown-netbsd",
-            "i686-unknown-openbsd",
-            "i686-unknown-uefi",
-            "i686-uwp-windows-gnu",
-            "i686-uwp-windows-msvc",
-            "i686-wrs-vxworks",
-            "mips64el-unknown-linux-gnuabi64",
-            "mips64el-unknown-linux-muslabi64",
-            "mips64-unknown-linux-gnuabi64",
-            "mips64-unknown-linux-muslabi64",
-            "mipsel-unknown-linux-gnu",
-            "mipsel-unknown-linux-musl",
-            "mipsel-unknown-linux-uclibc",
-            "mipsisa32r6el-unknown-linux-gnu",
-            "mipsisa32r6-unknown-linux-gnu",
-            "mipsisa64r6el-unknown-linux-gnuabi64",
-            "mipsisa64r6-unknown-linux-gnuabi64",
-            "mips-unknown-linux-gnu",
-            "mips-unknown-linux-musl",
-            "mips-unknown-linux-uclibc",
-            "msp430-none-elf",
-            "nvptx64-nvidia-cuda",
-            "powerpc64le-unknown-linux-gnu",
-            "powerpc64le-unknown-linux-musl",
-            "powerpc64-unknown-freebsd",
-            "powerpc64-unknown-linux-gnu",
-            "powerpc64-unknown-linux-musl",
-            "powerpc64-wrs-vxworks",
-            "powerpc-unknown-linux-gnu",
-            "powerpc-unknown-linux-gnuspe",
-            "powerpc-unknown-linux-musl",
-            "powerpc-unknown-netbsd",
-            "powerpc-wrs-vxworks",
-            "powerpc-wrs-vxworks-spe",
-            "riscv32imac-unknown-none-elf",
-            "riscv32imc-unknown-none-elf",
-            "riscv32i-unknown-none-elf",
-            "riscv64gc-unknown-linux-gnu",
-            "riscv64gc-unknown-none-elf",
-            "riscv64imac-unknown-none-elf",
-            "s390x-unknown-linux-gnu",
-            "sparc64-unknown-linux-gnu",
-            "sparc64-unknown-netbsd",
-            "sparc64-unknown-openbsd",
-            "sparc-unknown-linux-gnu",
-            "sparcv9-sun-solaris",
-            "thumbv6m-none-eabi",
-            "thumbv7a-pc-windows-msvc",
-            "thumbv7em-none-eabi",
-            "thumbv7em-none-eabihf",
-            "thumbv7m-none-eabi",
-            "thumbv7neon-linux-androideabi",
-            "thumbv7neon-unknown-linux-gnueabihf",
-            "thumbv7neon-unknown-linux-musleabihf",
-            "thumbv8m.base-none-eabi",
-            "thumbv8m.main-none-eabi",
-            "thumbv8m.main-none-eabihf",
-            "wasm32-experimental-emscripten",
-            "wasm32-unknown-emscripten",
-            "wasm32-unknown-unknown",
-            "wasm64-unknown-unknown",
-            "wasm32-wasi",
-            "wasm64-wasi",
-            "x86_64-apple-darwin",
-            "x86_64-apple-ios",
-            "x86_64-fortanix-unknown-sgx",
-            "x86_64-fuchsia",
-            "x86_64-linux-android",
-            "x86_64-linux-kernel",
-            "x86_64-apple-macosx10.7.0",
-            "x86_64-pc-solaris",
-            "x86_64-pc-windows-gnu",
-            "x86_64-pc-windows-msvc",
-            "x86_64-rumprun-netbsd",
-            "x86_64-sun-solaris",
-            "x86_64-unknown-bitrig",
-            "x86_64-unknown-cloudabi",
-            "x86_64-unknown-dragonfly",
-            "x86_64-unknown-freebsd",
-            "x86_64-unknown-haiku",
-            "x86_64-unknown-hermit",
-            "x86_64-unknown-hermit-kernel",
-            "x86_64-unknown-l4re-uclibc",
-            "x86_64-unknown-linux-gnu",
-            "x86_64-unknown-linux-gnux32",
-            "x86_64-unknown-linux-musl",
-            "x86_64-unknown-netbsd",
-            "x86_64-unknown-openbsd",
-            "x86_64-unknown-redox",
-            "x86_64-unknown-uefi",
-            "x86_64-uwp-windows-gnu",
-            "x86_64-uwp-windows-msvc",
-            "x86_64-wrs-vxworks",
-        ];
-
-        for target in targets.iter() {
-            let t = Triple::from_str(target).expect("can't parse target");
+        for vector in crate::test_vectors::TEST_VECTORS {
+            let t = Triple::from_str(vector.triple).expect("can't parse target");
             assert_ne!(t.architecture, Architecture::Unknown);
-            assert_eq!(t.to_string(), *target);
+            assert_eq!(t.to_string(), vector.triple);
         }
     }
 
@@ -1348,6 +2413,360 @@ mod tests {
         assert_eq!(t.binary_format, BinaryFormat::Elf);
     }
 
+    #[test]
+    fn nvptx64_nvidia_cuda() {
+        let t = Triple::from_str("nvptx64-nvidia-cuda").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::Nvptx64);
+        assert_eq!(t.vendor, Vendor::Nvidia);
+        assert_eq!(t.operating_system, OperatingSystem::Cuda);
+        assert_eq!(t.environment, Environment::Unknown);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+    }
+
+    #[test]
+    fn x86_64_fortanix_unknown_sgx() {
+        let t = Triple::from_str("x86_64-fortanix-unknown-sgx").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::X86_64);
+        assert_eq!(t.vendor, Vendor::Fortanix);
+        assert_eq!(t.operating_system, OperatingSystem::Unknown);
+        assert_eq!(t.environment, Environment::Sgx);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        assert_eq!(
+            t.default_calling_convention(),
+            Ok(crate::CallingConvention::SystemV)
+        );
+        assert_eq!(t.data_model(), Ok(crate::CDataModel::LP64));
+    }
+
+    #[test]
+    fn powerpc64_ibm_aix() {
+        let t = Triple::from_str("powerpc64-ibm-aix").expect("can't parse target");
+        assert_eq!(t.vendor, Vendor::Ibm);
+        assert_eq!(t.operating_system, OperatingSystem::Aix);
+        assert_eq!(t.binary_format, BinaryFormat::Xcoff);
+    }
+
+    #[test]
+    fn s390x_ibm_zos() {
+        let t = Triple::from_str("s390x-ibm-zos").expect("can't parse target");
+        assert_eq!(t.vendor, Vendor::Ibm);
+        assert_eq!(t.operating_system, OperatingSystem::Zos);
+        assert_eq!(t.binary_format, BinaryFormat::Goff);
+
+        let t = Triple::from_str("s390x-ibm-linux-gnu").expect("can't parse target");
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+    }
+
+    #[test]
+    fn spirv() {
+        let t = Triple::from_str("spirv64-unknown-unknown").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::Spirv64);
+        assert_eq!(t.binary_format, BinaryFormat::Spirv);
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U64));
+
+        let t = Triple::from_str("spirv-unknown-unknown").expect("can't parse target");
+        assert_eq!(t.binary_format, BinaryFormat::Spirv);
+        assert_eq!(t.pointer_width(), Err(()));
+    }
+
+    #[test]
+    fn dxil() {
+        let t = Triple::from_str("dxil-unknown-unknown").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::Dxil);
+        assert_eq!(t.binary_format, BinaryFormat::DxContainer);
+
+        for (target, major, minor, environment) in [
+            ("dxil-pc-shadermodel6.0-pixel", 6, 0, Environment::Pixel),
+            ("dxil-pc-shadermodel6.3-library", 6, 3, Environment::Library),
+            ("dxil-pc-shadermodel6.5-mesh", 6, 5, Environment::Mesh),
+            (
+                "dxil-pc-shadermodel6.5-amplification",
+                6,
+                5,
+                Environment::Amplification,
+            ),
+            ("dxil-pc-shadermodel6.6-compute", 6, 6, Environment::Compute),
+            (
+                "dxil-pc-shadermodel6.7-raygeneration",
+                6,
+                7,
+                Environment::RayGeneration,
+            ),
+            (
+                "dxil-pc-shadermodel6.8-closesthit",
+                6,
+                8,
+                Environment::ClosestHit,
+            ),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.architecture, Architecture::Dxil);
+            assert_eq!(t.vendor, Vendor::Pc);
+            assert_eq!(
+                t.operating_system,
+                OperatingSystem::ShaderModel {
+                    major: *major,
+                    minor: *minor
+                }
+            );
+            assert_eq!(t.environment, *environment);
+            assert_eq!(t.binary_format, BinaryFormat::DxContainer);
+            assert_eq!(t.to_string(), *target);
+        }
+
+        assert!(Triple::from_str("dxil-pc-shadermodel6-library").is_err());
+        assert!(Triple::from_str("dxil-pc-shadermodel6.x-library").is_err());
+    }
+
+    #[test]
+    fn bare_elf() {
+        let t = Triple::from_str("m68k-elf").expect("can't parse target");
+        assert_eq!(t.architecture, Architecture::M68k);
+        assert_eq!(t.vendor, Vendor::Unknown);
+        assert_eq!(t.operating_system, OperatingSystem::None_);
+        assert_eq!(t.environment, Environment::Unknown);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        assert_eq!(t.to_string(), "m68k-unknown-none-elf");
+        assert_eq!(Triple::from_str(&t.to_string()), Ok(t));
+
+        let t = Triple::from_str("riscv32imac-unknown-none-elf").expect("can't parse target");
+        assert_eq!(t.operating_system, OperatingSystem::None_);
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        assert_eq!(Triple::from_str("riscv32imac-unknown-elf"), Ok(t.clone()));
+        assert_eq!(Triple::from_str("riscv32imac-elf"), Ok(t));
+    }
+
+    #[test]
+    fn msvc_versions() {
+        let t = Triple::from_str("x86_64-pc-windows-msvc.vc142.mt").expect("can't parse target");
+        assert!(t.environment.is_msvc());
+        assert_eq!(t.environment.msvc_toolset(), Some(MsvcToolset::Vc142));
+        assert_eq!(t.environment.msvc_crt(), Some(MsvcCrt::Static));
+        assert_eq!(t.binary_format, BinaryFormat::Coff);
+        assert_ne!(
+            t,
+            Triple::from_str("x86_64-pc-windows-msvc.vc143.mt").unwrap()
+        );
+        assert_ne!(
+            t,
+            Triple::from_str("x86_64-pc-windows-msvc.vc142.md").unwrap()
+        );
+
+        let t = Triple::from_str("x86_64-pc-windows-msvc").expect("can't parse target");
+        assert!(t.environment.is_msvc());
+        assert_eq!(t.environment.msvc_toolset(), None);
+        assert_eq!(t.environment.msvc_crt(), None);
+
+        assert_eq!(Environment::msvc(None, None), Environment::Msvc);
+        assert_eq!(
+            Environment::msvc(Some(MsvcToolset::Vc143), None).to_string(),
+            "msvc.vc143"
+        );
+        assert_eq!(
+            Environment::msvc(None, Some(MsvcCrt::StaticDebug)).to_string(),
+            "msvc.mtd"
+        );
+        assert_eq!(
+            Environment::msvc(Some(MsvcToolset::Vc141), Some(MsvcCrt::Dynamic)),
+            Environment::from_str("msvc.vc141.md").unwrap()
+        );
+
+        assert!(Triple::from_str("x86_64-pc-windows-msvc.").is_err());
+        assert!(Triple::from_str("x86_64-pc-windows-msvc.vc99").is_err());
+        assert!(Triple::from_str("x86_64-pc-windows-msvc.mt.vc143").is_err());
+        assert!(Triple::from_str("x86_64-pc-windows-msvc.vc143.mt.mt").is_err());
+    }
+
+    #[test]
+    fn mingw_crts() {
+        let t = Triple::from_str("x86_64-pc-windows-gnu.ucrt").expect("can't parse target");
+        assert_eq!(t.environment, Environment::GnuUcrt);
+        assert_eq!(t.environment.mingw_crt(), Some(MingwCrt::Ucrt));
+        assert_eq!(t.binary_format, BinaryFormat::Coff);
+
+        let t = Triple::from_str("i686-pc-windows-gnu.msvcrt").expect("can't parse target");
+        assert_eq!(t.environment.mingw_crt(), Some(MingwCrt::Msvcrt));
+
+        let t = Triple::from_str("x86_64-pc-windows-gnu").expect("can't parse target");
+        assert_eq!(t.environment.mingw_crt(), None);
+    }
+
+    #[test]
+    fn riscv_extensions() {
+        let riscv = RiscvArchitecture::from_str("riscv64gc").unwrap();
+        assert_eq!(riscv.base_width(), PointerWidth::U64);
+        for extension in [
+            RiscvExtension::I,
+            RiscvExtension::M,
+            RiscvExtension::A,
+            RiscvExtension::F,
+            RiscvExtension::D,
+            RiscvExtension::C,
+        ]
+        .iter()
+        {
+            assert!(riscv.has_extension(*extension), "{}", extension);
+        }
+        assert!(!riscv.has_extension(RiscvExtension::V));
+        assert_eq!(RiscvArchitecture::from_str("riscv64imafdc"), Ok(riscv));
+        assert_eq!(riscv.to_string(), "riscv64gc");
+
+        let riscv = RiscvArchitecture::from_str("riscv32imafc").unwrap();
+        assert_eq!(riscv.base_width(), PointerWidth::U32);
+        assert!(riscv.has_extension(RiscvExtension::F));
+        assert!(!riscv.has_extension(RiscvExtension::D));
+        assert_eq!(
+            riscv,
+            RiscvArchitecture::riscv32()
+                .with_extension(RiscvExtension::I)
+                .with_extension(RiscvExtension::M)
+                .with_extension(RiscvExtension::A)
+                .with_extension(RiscvExtension::F)
+                .with_extension(RiscvExtension::C)
+        );
+
+        for s in [
+            "riscv32",
+            "riscv64",
+            "riscv32i",
+            "riscv32e",
+            "riscv32emc",
+            "riscv32imac",
+            "riscv32gc",
+            "riscv64gcv",
+        ]
+        .iter()
+        {
+            let riscv = RiscvArchitecture::from_str(s).unwrap();
+            assert_eq!(riscv.to_string(), *s);
+        }
+        for s in [
+            "riscv",
+            "riscv128i",
+            "riscv32ci",
+            "riscv32ie",
+            "riscv32ii",
+            "riscv32x",
+            "riscv64gm",
+        ]
+        .iter()
+        {
+            assert_eq!(RiscvArchitecture::from_str(s), Err(()), "{}", s);
+        }
+
+        assert_eq!(
+            Architecture::from_str("riscv32imafc"),
+            Ok(Architecture::Riscv(riscv))
+        );
+        assert_eq!(
+            Architecture::from_str("riscv64im").unwrap().pointer_width(),
+            Ok(PointerWidth::U64)
+        );
+        assert_eq!(
+            Architecture::Riscv(RiscvArchitecture::riscv32()).pointer_width(),
+            Ok(PointerWidth::U32)
+        );
+
+        // The I and E base ISAs are exclusive, including through `g`.
+        let rv64g = RiscvArchitecture::from_str("riscv64g").unwrap();
+        assert_eq!(rv64g.checked_with_extension(RiscvExtension::E), None);
+        let rv32e = RiscvArchitecture::from_str("riscv32e").unwrap();
+        assert_eq!(rv32e.checked_with_extension(RiscvExtension::I), None);
+        assert_eq!(
+            rv32e.checked_with_extension(RiscvExtension::M),
+            Some(RiscvArchitecture::from_str("riscv32em").unwrap())
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn riscv_exclusive_base_isas() {
+        let _ = RiscvArchitecture::riscv32()
+            .with_extension(RiscvExtension::I)
+            .with_extension(RiscvExtension::E);
+    }
+
+    #[test]
+    fn espressif() {
+        let t = Triple::from_str("riscv32imc-esp-espidf").expect("can't parse target");
+        assert_eq!(
+            t.architecture,
+            Architecture::Riscv(
+                RiscvArchitecture::riscv32()
+                    .with_extension(RiscvExtension::I)
+                    .with_extension(RiscvExtension::M)
+                    .with_extension(RiscvExtension::C)
+            )
+        );
+        assert_eq!(t.vendor, Vendor::Espressif(EspressifVendor::Esp));
+        assert_eq!(t.operating_system, OperatingSystem::Espidf);
+        assert_eq!(t.to_string(), "riscv32imc-esp-espidf");
+        assert_eq!(Triple::from_str("riscv32imc-espressif-espidf").unwrap(), t);
+
+        for (target, vendor, os) in [
+            (
+                "xtensa-esp32-espidf",
+                EspressifVendor::Esp32,
+                OperatingSystem::Espidf,
+            ),
+            (
+                "xtensa-esp32-none-elf",
+                EspressifVendor::Esp32,
+                OperatingSystem::None_,
+            ),
+            (
+                "xtensa-esp32s2-espidf",
+                EspressifVendor::Esp32s2,
+                OperatingSystem::Espidf,
+            ),
+            (
+                "xtensa-esp32s2-none-elf",
+                EspressifVendor::Esp32s2,
+                OperatingSystem::None_,
+            ),
+            (
+                "xtensa-esp32s3-espidf",
+                EspressifVendor::Esp32s3,
+                OperatingSystem::Espidf,
+            ),
+            (
+                "xtensa-esp32s3-none-elf",
+                EspressifVendor::Esp32s3,
+                OperatingSystem::None_,
+            ),
+        ]
+        .iter()
+        {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.architecture, Architecture::Xtensa);
+            assert_eq!(t.vendor, Vendor::Espressif(*vendor));
+            assert_eq!(t.operating_system, *os);
+            assert_eq!(t.binary_format, BinaryFormat::Elf);
+            assert_eq!(t.to_string(), *target);
+        }
+    }
+
+    #[test]
+    fn sony_vendor_aliases() {
+        for target in ["x86_64-sony-ps4", "x86_64-sie-ps4", "x86_64-scei-ps4"].iter() {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.vendor, Vendor::Sony);
+            assert_eq!(t.operating_system, OperatingSystem::Ps4);
+            assert_eq!(t.vendor.to_string(), "sony");
+            assert_eq!(t.to_string(), "x86_64-scei-ps4");
+        }
+        for target in ["x86_64-sony-ps5", "x86_64-sie-ps5", "x86_64-scei-ps5"].iter() {
+            let t = Triple::from_str(target).expect("can't parse target");
+            assert_eq!(t.vendor, Vendor::Sony);
+            assert_eq!(t.operating_system, OperatingSystem::Ps5);
+            assert_eq!(t.to_string(), "x86_64-sie-ps5");
+        }
+        let t = Triple::from_str("mipsel-sony-psp").expect("can't parse target");
+        assert_eq!(t.to_string(), "mipsel-sony-psp");
+    }
+
     #[test]
     fn custom_vendors() {
         // Test various invalid cases.
@@ -1430,4 +2849,112 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn arch_families() {
+        let pairs = [
+            ("i686", ArchFamily::X86),
+            ("x86_64", ArchFamily::X86),
+            ("thumbv7em", ArchFamily::Arm),
+            ("aarch64", ArchFamily::Arm),
+            ("riscv64gc", ArchFamily::RiscV),
+            ("mipsel", ArchFamily::Mips),
+            ("powerpc64le", ArchFamily::PowerPc),
+            ("sparcv9", ArchFamily::Sparc),
+            ("wasm32", ArchFamily::Wasm),
+            ("nvptx64", ArchFamily::Gpu),
+            ("spirv64", ArchFamily::Gpu),
+            ("unknown", ArchFamily::Unknown),
+        ];
+        for (arch, family) in pairs.iter() {
+            assert_eq!(
+                Architecture::from_str(arch).unwrap().family(),
+                *family,
+                "{}",
+                arch
+            );
+        }
+    }
+
+    #[test]
+    fn kernel_families() {
+        let pairs = [
+            ("linux", KernelFamily::Linux),
+            ("windows", KernelFamily::Nt),
+            ("darwin", KernelFamily::Xnu),
+            ("macosx10.7.0", KernelFamily::Xnu),
+            ("ios", KernelFamily::Xnu),
+            ("freebsd", KernelFamily::Bsd(Bsd::Freebsd)),
+            ("netbsd", KernelFamily::Bsd(Bsd::Netbsd)),
+            ("solaris", KernelFamily::Solarish),
+            ("wasi", KernelFamily::Wasi),
+            ("none", KernelFamily::None_),
+            ("fuchsia", KernelFamily::Other),
+            ("unknown", KernelFamily::Other),
+        ];
+        for (os, family) in pairs.iter() {
+            assert_eq!(
+                OperatingSystem::from_str(os).unwrap().kernel_family(),
+                *family,
+                "{}",
+                os
+            );
+        }
+    }
+
+    #[test]
+    fn gdb_arch_names() {
+        let pairs = [
+            ("i686", "i386"),
+            ("x86_64", "i386:x86-64"),
+            ("aarch64", "aarch64"),
+            ("armv7", "armv7"),
+            ("thumbv7em", "armv7e-m"),
+            ("thumbv6m", "armv6-m"),
+            ("thumbv8m.main", "armv8-m.main"),
+            ("riscv64gc", "riscv:rv64"),
+            ("powerpc64le", "powerpc:common64"),
+        ];
+        for (arch, name) in pairs.iter() {
+            assert_eq!(
+                Architecture::from_str(arch).unwrap().gdb_arch_name(),
+                Ok(*name)
+            );
+        }
+        assert_eq!(Architecture::Wasm32.gdb_arch_name(), Err(()));
+    }
+
+    #[test]
+    fn vector_widths() {
+        let cases = [
+            (
+                "x86_64",
+                Some(VectorWidth::Fixed(512)),
+                Some(VectorWidth::Fixed(128)),
+            ),
+            ("i586", Some(VectorWidth::Fixed(512)), None),
+            (
+                "aarch64",
+                Some(VectorWidth::Scalable { max: 2048 }),
+                Some(VectorWidth::Fixed(128)),
+            ),
+            ("armv7", Some(VectorWidth::Fixed(128)), None),
+            ("thumbv7em", None, None),
+            ("riscv64", Some(VectorWidth::Scalable { max: 65536 }), None),
+            ("riscv64gc", None, None),
+            ("riscv32imac", None, None),
+            (
+                "riscv64gcv",
+                Some(VectorWidth::Scalable { max: 65536 }),
+                Some(VectorWidth::Scalable { max: 65536 }),
+            ),
+            ("wasm32", Some(VectorWidth::Fixed(128)), None),
+            ("unknown", None, None),
+        ];
+        for (arch, max, baseline) in cases.iter() {
+            let arch = Architecture::from_str(arch).unwrap();
+            assert_eq!(arch.max_vector_width(), *max, "{}", arch);
+            assert_eq!(arch.baseline_vector_width(), *baseline, "{}", arch);
+        }
+    }
 }
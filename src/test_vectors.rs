@@ -0,0 +1,1818 @@
+//! A corpus of known triples along with the components they're expected to
+//! parse into, so that crates building on this one can check their handling
+//! of triples against the same cases and notice when an upgrade changes them.
+
+use crate::targets::{
+    Aarch64Architecture, Architecture, ArmArchitecture, BinaryFormat, Environment, EspressifVendor,
+    Mips32Architecture, Mips64Architecture, MsvcCrt, MsvcToolset, OperatingSystem,
+    RiscvArchitecture, RiscvExtension, Vendor, X86_32Architecture,
+};
+
+/// A triple string and the components it's expected to parse into. Every
+/// test vector also displays as its `triple` string again.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TestVector {
+    /// The triple string.
+    pub triple: &'static str,
+    /// The expected architecture.
+    pub architecture: Architecture,
+    /// The expected vendor.
+    pub vendor: Vendor,
+    /// The expected operating system.
+    pub operating_system: OperatingSystem,
+    /// The expected environment.
+    pub environment: Environment,
+    /// The expected binary format.
+    pub binary_format: BinaryFormat,
+}
+
+/// The test vectors. These are constructed from the targets emitted by
+/// `rustup target list` and `rustc +nightly --print target-list`, along with
+/// targets contributors have added.
+pub static TEST_VECTORS: &[TestVector] = &[
+    TestVector {
+        triple: "aarch64-apple-ios",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Ios,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "aarch64-apple-ios-macabi",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Ios,
+        environment: Environment::MacABI,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "aarch64-apple-ios-sim",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Ios,
+        environment: Environment::Sim,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "aarch64-apple-tvos",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Tvos,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "aarch64-apple-tvos-sim",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Tvos,
+        environment: Environment::Sim,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "aarch64-apple-watchos-sim",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Watchos,
+        environment: Environment::Sim,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "aarch64-fuchsia",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Fuchsia,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-kmc-solid_asp3",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Kmc,
+        operating_system: OperatingSystem::SolidAsp3,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-linux-android",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Android,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-nintendo-switch-freestanding",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Nintendo,
+        operating_system: OperatingSystem::Switch,
+        environment: Environment::Freestanding,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-openwrt-linux-musl",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::OpenWrt,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-pc-windows-gnullvm",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Gnullvm,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "aarch64-pc-windows-msvc",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "aarch64-unknown-cloudabi",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Cloudabi,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-unknown-freebsd",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Freebsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-unknown-hermit",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Hermit,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-unknown-linux-gnu",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-unknown-linux-gnu_ilp32",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::GnuIlp32,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-unknown-linux-mlibc",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Mlibc,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-unknown-linux-musl",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-unknown-netbsd",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Netbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-unknown-none",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "aarch64-unknown-none-softfloat",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Softfloat,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "aarch64-unknown-openbsd",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Openbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-unknown-redox",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Redox,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "aarch64-uwp-windows-msvc",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Uwp,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "aarch64-wrs-vxworks",
+        architecture: Architecture::Aarch64(Aarch64Architecture::Aarch64),
+        vendor: Vendor::Wrs,
+        operating_system: OperatingSystem::VxWorks,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "amdgcn-amd-amdhsa",
+        architecture: Architecture::AmdGcn,
+        vendor: Vendor::Amd,
+        operating_system: OperatingSystem::AmdHsa,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "amdgcn-amd-amdhsa-amdgiz",
+        architecture: Architecture::AmdGcn,
+        vendor: Vendor::Amd,
+        operating_system: OperatingSystem::AmdHsa,
+        environment: Environment::AmdGiz,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "amdgcn-amd-amdpal",
+        architecture: Architecture::AmdGcn,
+        vendor: Vendor::Amd,
+        operating_system: OperatingSystem::AmdPal,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armebv7r-none-eabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armebv7r),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armebv7r-none-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armebv7r),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "arm-linux-androideabi",
+        architecture: Architecture::Arm(ArmArchitecture::Arm),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Androideabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "arm-unknown-linux-gnueabi",
+        architecture: Architecture::Arm(ArmArchitecture::Arm),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnueabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "arm-unknown-linux-gnueabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Arm),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnueabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "arm-unknown-linux-musleabi",
+        architecture: Architecture::Arm(ArmArchitecture::Arm),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musleabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "arm-unknown-linux-musleabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Arm),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musleabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv4t-unknown-linux-gnueabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armv4t),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnueabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv5te-unknown-linux-gnueabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armv5te),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnueabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv5te-unknown-linux-musleabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armv5te),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musleabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv6-unknown-freebsd",
+        architecture: Architecture::Arm(ArmArchitecture::Armv6),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Freebsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv6-unknown-netbsd-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv6),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Netbsd,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv6k-nintendo-3ds",
+        architecture: Architecture::Arm(ArmArchitecture::Armv6k),
+        vendor: Vendor::Nintendo,
+        operating_system: OperatingSystem::Nintendo3ds,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7a-kmc-solid_asp3-eabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7a),
+        vendor: Vendor::Kmc,
+        operating_system: OperatingSystem::SolidAsp3,
+        environment: Environment::Eabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7a-kmc-solid_asp3-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7a),
+        vendor: Vendor::Kmc,
+        operating_system: OperatingSystem::SolidAsp3,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7a-none-eabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7a),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7a-none-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7a),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7a-none-newlibeabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7a),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Newlibeabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7-apple-ios",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Ios,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "armv7-linux-androideabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Androideabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7r-none-eabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7r),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7r-none-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7r),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7s-apple-ios",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7s),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Ios,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "armv7-sony-vita-newlibeabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Sony,
+        operating_system: OperatingSystem::Vita,
+        environment: Environment::Newlibeabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7-unknown-cloudabi-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Cloudabi,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7-unknown-freebsd",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Freebsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7-unknown-linux-gnueabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnueabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7-unknown-linux-gnueabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnueabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7-unknown-linux-musleabi",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musleabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7-unknown-linux-musleabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musleabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7-unknown-netbsd-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Netbsd,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "armv7-wrs-vxworks-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Armv7),
+        vendor: Vendor::Wrs,
+        operating_system: OperatingSystem::VxWorks,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "asmjs-unknown-emscripten",
+        architecture: Architecture::Asmjs,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Emscripten,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "dxil-pc-shadermodel6.3-library",
+        architecture: Architecture::Dxil,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::ShaderModel { major: 6, minor: 3 },
+        environment: Environment::Library,
+        binary_format: BinaryFormat::DxContainer,
+    },
+    TestVector {
+        triple: "dxil-unknown-unknown",
+        architecture: Architecture::Dxil,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Unknown,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::DxContainer,
+    },
+    TestVector {
+        triple: "hexagon-unknown-linux-musl",
+        architecture: Architecture::Hexagon,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i386-apple-ios",
+        architecture: Architecture::X86_32(X86_32Architecture::I386),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Ios,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "i586-pc-windows-msvc",
+        architecture: Architecture::X86_32(X86_32Architecture::I586),
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "i586-unknown-linux-gnu",
+        architecture: Architecture::X86_32(X86_32Architecture::I586),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i586-unknown-linux-musl",
+        architecture: Architecture::X86_32(X86_32Architecture::I586),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-apple-darwin",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Darwin,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "i686-linux-android",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Android,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-apple-macosx10.7.0",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::MacOSX {
+            major: 10,
+            minor: 7,
+            patch: 0,
+        },
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "i686-pc-windows-gnu",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "i686-pc-windows-gnullvm",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Gnullvm,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "i686-pc-windows-msvc",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "i686-unknown-cloudabi",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Cloudabi,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-unknown-dragonfly",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Dragonfly,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-unknown-freebsd",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Freebsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-unknown-haiku",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Haiku,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-unknown-linux-gnu",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-unknown-linux-musl",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-unknown-netbsd",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Netbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-unknown-openbsd",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Openbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-unknown-uefi",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Uefi,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "i686-uwp-windows-gnu",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Uwp,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "i686-uwp-windows-msvc",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Uwp,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "i686-win7-windows-msvc",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Win7,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "i686-wrs-vxworks",
+        architecture: Architecture::X86_32(X86_32Architecture::I686),
+        vendor: Vendor::Wrs,
+        operating_system: OperatingSystem::VxWorks,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "mips64el-unknown-linux-gnuabi64",
+        architecture: Architecture::Mips64(Mips64Architecture::Mips64el),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnuabi64,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mips64el-unknown-linux-muslabi64",
+        architecture: Architecture::Mips64(Mips64Architecture::Mips64el),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Muslabi64,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mips64-unknown-linux-gnuabi64",
+        architecture: Architecture::Mips64(Mips64Architecture::Mips64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnuabi64,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mips64-unknown-linux-muslabi64",
+        architecture: Architecture::Mips64(Mips64Architecture::Mips64),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Muslabi64,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsel-openwrt-linux-musl",
+        architecture: Architecture::Mips32(Mips32Architecture::Mipsel),
+        vendor: Vendor::OpenWrt,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsel-unknown-linux-gnu",
+        architecture: Architecture::Mips32(Mips32Architecture::Mipsel),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsel-unknown-linux-musl",
+        architecture: Architecture::Mips32(Mips32Architecture::Mipsel),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsel-unknown-linux-uclibc",
+        architecture: Architecture::Mips32(Mips32Architecture::Mipsel),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Uclibc,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsisa32r6el-unknown-linux-gnu",
+        architecture: Architecture::Mips32(Mips32Architecture::Mipsisa32r6el),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsisa32r6-unknown-linux-gnu",
+        architecture: Architecture::Mips32(Mips32Architecture::Mipsisa32r6),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsisa64r6el-unknown-linux-gnuabi64",
+        architecture: Architecture::Mips64(Mips64Architecture::Mipsisa64r6el),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnuabi64,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsisa64r6-unknown-linux-gnuabi64",
+        architecture: Architecture::Mips64(Mips64Architecture::Mipsisa64r6),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnuabi64,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mips-openwrt-linux-musl",
+        architecture: Architecture::Mips32(Mips32Architecture::Mips),
+        vendor: Vendor::OpenWrt,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mips-unknown-linux-gnu",
+        architecture: Architecture::Mips32(Mips32Architecture::Mips),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mips-unknown-linux-musl",
+        architecture: Architecture::Mips32(Mips32Architecture::Mips),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mips-unknown-linux-uclibc",
+        architecture: Architecture::Mips32(Mips32Architecture::Mips),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Uclibc,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsel-sony-psp",
+        architecture: Architecture::Mips32(Mips32Architecture::Mipsel),
+        vendor: Vendor::Sony,
+        operating_system: OperatingSystem::Psp,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "mipsel-sony-psx",
+        architecture: Architecture::Mips32(Mips32Architecture::Mipsel),
+        vendor: Vendor::Sony,
+        operating_system: OperatingSystem::Psx,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "m68k-unknown-linux-gnu",
+        architecture: Architecture::M68k,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "msp430-none-elf",
+        architecture: Architecture::Msp430,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "nvptx64-nvidia-cuda",
+        architecture: Architecture::Nvptx64,
+        vendor: Vendor::Nvidia,
+        operating_system: OperatingSystem::Cuda,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc64le-unknown-linux-gnu",
+        architecture: Architecture::Powerpc64le,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc64le-unknown-linux-musl",
+        architecture: Architecture::Powerpc64le,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc64-ibm-aix",
+        architecture: Architecture::Powerpc64,
+        vendor: Vendor::Ibm,
+        operating_system: OperatingSystem::Aix,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Xcoff,
+    },
+    TestVector {
+        triple: "powerpc64-unknown-freebsd",
+        architecture: Architecture::Powerpc64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Freebsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc64-unknown-linux-gnu",
+        architecture: Architecture::Powerpc64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc64-unknown-linux-musl",
+        architecture: Architecture::Powerpc64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc64-wrs-vxworks",
+        architecture: Architecture::Powerpc64,
+        vendor: Vendor::Wrs,
+        operating_system: OperatingSystem::VxWorks,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "powerpc-unknown-linux-gnu",
+        architecture: Architecture::Powerpc,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc-unknown-linux-gnuspe",
+        architecture: Architecture::Powerpc,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnuspe,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc-unknown-linux-musl",
+        architecture: Architecture::Powerpc,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc-unknown-netbsd",
+        architecture: Architecture::Powerpc,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Netbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "powerpc-wrs-vxworks",
+        architecture: Architecture::Powerpc,
+        vendor: Vendor::Wrs,
+        operating_system: OperatingSystem::VxWorks,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "powerpc-wrs-vxworks-spe",
+        architecture: Architecture::Powerpc,
+        vendor: Vendor::Wrs,
+        operating_system: OperatingSystem::VxWorks,
+        environment: Environment::Spe,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "powerpc-unknown-none-newlib",
+        architecture: Architecture::Powerpc,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Newlib,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "riscv32imac-unknown-none-elf",
+        architecture: Architecture::Riscv(
+            RiscvArchitecture::riscv32()
+                .with_extension(RiscvExtension::I)
+                .with_extension(RiscvExtension::M)
+                .with_extension(RiscvExtension::A)
+                .with_extension(RiscvExtension::C),
+        ),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "riscv32imc-unknown-none-elf",
+        architecture: Architecture::Riscv(
+            RiscvArchitecture::riscv32()
+                .with_extension(RiscvExtension::I)
+                .with_extension(RiscvExtension::M)
+                .with_extension(RiscvExtension::C),
+        ),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "riscv32i-unknown-none-elf",
+        architecture: Architecture::Riscv(
+            RiscvArchitecture::riscv32().with_extension(RiscvExtension::I),
+        ),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "riscv32imc-esp-espidf",
+        architecture: Architecture::Riscv(
+            RiscvArchitecture::riscv32()
+                .with_extension(RiscvExtension::I)
+                .with_extension(RiscvExtension::M)
+                .with_extension(RiscvExtension::C),
+        ),
+        vendor: Vendor::Espressif(EspressifVendor::Esp),
+        operating_system: OperatingSystem::Espidf,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "riscv64gc-unknown-linux-gnu",
+        architecture: Architecture::Riscv(
+            RiscvArchitecture::riscv64()
+                .with_extension(RiscvExtension::I)
+                .with_extension(RiscvExtension::M)
+                .with_extension(RiscvExtension::A)
+                .with_extension(RiscvExtension::F)
+                .with_extension(RiscvExtension::D)
+                .with_extension(RiscvExtension::C),
+        ),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "riscv64gc-unknown-none-elf",
+        architecture: Architecture::Riscv(
+            RiscvArchitecture::riscv64()
+                .with_extension(RiscvExtension::I)
+                .with_extension(RiscvExtension::M)
+                .with_extension(RiscvExtension::A)
+                .with_extension(RiscvExtension::F)
+                .with_extension(RiscvExtension::D)
+                .with_extension(RiscvExtension::C),
+        ),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "riscv64imac-unknown-none-elf",
+        architecture: Architecture::Riscv(
+            RiscvArchitecture::riscv64()
+                .with_extension(RiscvExtension::I)
+                .with_extension(RiscvExtension::M)
+                .with_extension(RiscvExtension::A)
+                .with_extension(RiscvExtension::C),
+        ),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "s390x-ibm-linux-gnu",
+        architecture: Architecture::S390x,
+        vendor: Vendor::Ibm,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "s390x-ibm-zos",
+        architecture: Architecture::S390x,
+        vendor: Vendor::Ibm,
+        operating_system: OperatingSystem::Zos,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Goff,
+    },
+    TestVector {
+        triple: "s390x-unknown-linux-gnu",
+        architecture: Architecture::S390x,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "sparc64-unknown-linux-gnu",
+        architecture: Architecture::Sparc64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "sparc64-unknown-netbsd",
+        architecture: Architecture::Sparc64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Netbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "sparc64-unknown-openbsd",
+        architecture: Architecture::Sparc64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Openbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "sparc-unknown-linux-gnu",
+        architecture: Architecture::Sparc,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "sparcv9-sun-solaris",
+        architecture: Architecture::Sparcv9,
+        vendor: Vendor::Sun,
+        operating_system: OperatingSystem::Solaris,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "spirv-unknown-unknown",
+        architecture: Architecture::Spirv,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Unknown,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Spirv,
+    },
+    TestVector {
+        triple: "spirv32-unknown-unknown",
+        architecture: Architecture::Spirv32,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Unknown,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Spirv,
+    },
+    TestVector {
+        triple: "spirv64-unknown-unknown",
+        architecture: Architecture::Spirv64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Unknown,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Spirv,
+    },
+    TestVector {
+        triple: "thumbv6m-none-eabi",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv6m),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "thumbv7a-pc-windows-msvc",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv7a),
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "thumbv7em-none-eabi",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv7em),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "thumbv7em-none-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv7em),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "thumbv7em-none-eabihf-ihex",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv7em),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Ihex,
+    },
+    TestVector {
+        triple: "thumbv7em-none-eabihf-raw",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv7em),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Raw,
+    },
+    TestVector {
+        triple: "thumbv7m-none-eabi",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv7m),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "thumbv7neon-linux-androideabi",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv7neon),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Androideabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "thumbv7neon-unknown-linux-gnueabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv7neon),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnueabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "thumbv7neon-unknown-linux-musleabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv7neon),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musleabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "thumbv8m.base-none-eabi",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv8mBase),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "thumbv8m.main-none-eabi",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv8mMain),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabi,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "thumbv8m.main-none-eabihf",
+        architecture: Architecture::Arm(ArmArchitecture::Thumbv8mMain),
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Eabihf,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "wasm32-experimental-emscripten",
+        architecture: Architecture::Wasm32,
+        vendor: Vendor::Experimental,
+        operating_system: OperatingSystem::Emscripten,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Wasm,
+    },
+    TestVector {
+        triple: "wasm32-unknown-emscripten",
+        architecture: Architecture::Wasm32,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Emscripten,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Wasm,
+    },
+    TestVector {
+        triple: "wasm32-unknown-unknown",
+        architecture: Architecture::Wasm32,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Unknown,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Wasm,
+    },
+    TestVector {
+        triple: "wasm64-unknown-unknown",
+        architecture: Architecture::Wasm64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Unknown,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Wasm,
+    },
+    TestVector {
+        triple: "wasm32-wasi",
+        architecture: Architecture::Wasm32,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Wasi,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Wasm,
+    },
+    TestVector {
+        triple: "wasm64-wasi",
+        architecture: Architecture::Wasm64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Wasi,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Wasm,
+    },
+    TestVector {
+        triple: "x86_64-apple-darwin",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Darwin,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "x86_64-apple-ios",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Ios,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "x86_64-apple-ios-macabi",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Ios,
+        environment: Environment::MacABI,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "x86_64-apple-tvos",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Tvos,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "x86_64-apple-watchos-sim",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::Watchos,
+        environment: Environment::Sim,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "x86_64-fortanix-unknown-sgx",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Fortanix,
+        operating_system: OperatingSystem::Unknown,
+        environment: Environment::Sgx,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-fuchsia",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Fuchsia,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-linux-android",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Android,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-linux-kernel",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Kernel,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-apple-macosx10.7.0",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Apple,
+        operating_system: OperatingSystem::MacOSX {
+            major: 10,
+            minor: 7,
+            patch: 0,
+        },
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Macho,
+    },
+    TestVector {
+        triple: "x86_64-pc-solaris",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Solaris,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-pc-windows-gnu",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-pc-windows-gnu.msvcrt",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::GnuMsvcrt,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-pc-windows-gnu.ucrt",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::GnuUcrt,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-pc-windows-gnullvm",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Gnullvm,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-pc-windows-msvc",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-pc-windows-msvc.vc143",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::msvc(Some(MsvcToolset::Vc143), None),
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-pc-windows-msvc.vc142.mt",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::msvc(Some(MsvcToolset::Vc142), Some(MsvcCrt::Static)),
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-pc-windows-msvc.mdd",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Pc,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::msvc(None, Some(MsvcCrt::DynamicDebug)),
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-rumprun-netbsd",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Rumprun,
+        operating_system: OperatingSystem::Netbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-scei-ps4",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Sony,
+        operating_system: OperatingSystem::Ps4,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-sie-ps5",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Sony,
+        operating_system: OperatingSystem::Ps5,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-sun-solaris",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Sun,
+        operating_system: OperatingSystem::Solaris,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unikraft-linux-musl",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unikraft,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-bitrig",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Bitrig,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-cloudabi",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Cloudabi,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-dragonfly",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Dragonfly,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-freebsd",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Freebsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-haiku",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Haiku,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-hermit",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Hermit,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-hermit-kernel",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Hermit,
+        environment: Environment::Kernel,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-l4re-uclibc",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::L4re,
+        environment: Environment::Uclibc,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-linux-gnu",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-linux-gnux32",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Gnux32,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-linux-mlibc",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Mlibc,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-linux-musl",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Musl,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-linux-relibc",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Linux,
+        environment: Environment::Relibc,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-managarm-mlibc",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Managarm,
+        environment: Environment::Mlibc,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-netbsd",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Netbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-openbsd",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Openbsd,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-redox",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Redox,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-unknown-uefi",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Unknown,
+        operating_system: OperatingSystem::Uefi,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "x86_64-uwp-windows-gnu",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Uwp,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Gnu,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-uwp-windows-msvc",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Uwp,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-win7-windows-msvc",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Win7,
+        operating_system: OperatingSystem::Windows,
+        environment: Environment::Msvc,
+        binary_format: BinaryFormat::Coff,
+    },
+    TestVector {
+        triple: "x86_64-wrs-vxworks",
+        architecture: Architecture::X86_64,
+        vendor: Vendor::Wrs,
+        operating_system: OperatingSystem::VxWorks,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Unknown,
+    },
+    TestVector {
+        triple: "xtensa-esp32-espidf",
+        architecture: Architecture::Xtensa,
+        vendor: Vendor::Espressif(EspressifVendor::Esp32),
+        operating_system: OperatingSystem::Espidf,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "xtensa-esp32-none-elf",
+        architecture: Architecture::Xtensa,
+        vendor: Vendor::Espressif(EspressifVendor::Esp32),
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "xtensa-esp32s2-espidf",
+        architecture: Architecture::Xtensa,
+        vendor: Vendor::Espressif(EspressifVendor::Esp32s2),
+        operating_system: OperatingSystem::Espidf,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "xtensa-esp32s2-none-elf",
+        architecture: Architecture::Xtensa,
+        vendor: Vendor::Espressif(EspressifVendor::Esp32s2),
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "xtensa-esp32s3-espidf",
+        architecture: Architecture::Xtensa,
+        vendor: Vendor::Espressif(EspressifVendor::Esp32s3),
+        operating_system: OperatingSystem::Espidf,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+    TestVector {
+        triple: "xtensa-esp32s3-none-elf",
+        architecture: Architecture::Xtensa,
+        vendor: Vendor::Espressif(EspressifVendor::Esp32s3),
+        operating_system: OperatingSystem::None_,
+        environment: Environment::Unknown,
+        binary_format: BinaryFormat::Elf,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Triple;
+    use alloc::string::ToString;
+    use core::str::FromStr;
+
+    #[test]
+    fn test_vectors() {
+        for vector in TEST_VECTORS {
+            let triple = Triple::from_str(vector.triple).expect("can't parse test vector");
+            assert_eq!(
+                triple.architecture, vector.architecture,
+                "{}",
+                vector.triple
+            );
+            assert_eq!(triple.vendor, vector.vendor, "{}", vector.triple);
+            assert_eq!(
+                triple.operating_system, vector.operating_system,
+                "{}",
+                vector.triple
+            );
+            assert_eq!(triple.environment, vector.environment, "{}", vector.triple);
+            assert_eq!(
+                triple.binary_format, vector.binary_format,
+                "{}",
+                vector.triple
+            );
+            assert_eq!(triple.to_string(), vector.triple);
+        }
+    }
+}
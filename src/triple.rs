@@ -3,10 +3,12 @@
 use crate::data_model::CDataModel;
 use crate::parse_error::ParseError;
 use crate::targets::{
-    default_binary_format, Architecture, ArmArchitecture, BinaryFormat, Environment,
-    OperatingSystem, Vendor,
+    default_binary_format, Abi, Architecture, ArmArchitecture, BinaryFormat, Environment,
+    Mips32Architecture, Mips64Architecture, OperatingSystem, RiscvExtension, Vendor,
 };
 use alloc::borrow::ToOwned;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
 use core::fmt;
 use core::str::FromStr;
 
@@ -29,7 +31,7 @@ pub enum PointerWidth {
 
 impl PointerWidth {
     /// Return the number of bits in a pointer.
-    pub fn bits(self) -> u8 {
+    pub const fn bits(self) -> u8 {
         match self {
             PointerWidth::U16 => 16,
             PointerWidth::U32 => 32,
@@ -40,7 +42,7 @@ impl PointerWidth {
     /// Return the number of bytes in a pointer.
     ///
     /// For these purposes, there are 8 bits in a byte.
-    pub fn bytes(self) -> u8 {
+    pub const fn bytes(self) -> u8 {
         match self {
             PointerWidth::U16 => 2,
             PointerWidth::U32 => 4,
@@ -49,6 +51,33 @@ impl PointerWidth {
     }
 }
 
+/// The native word size of an architecture.
+///
+/// Unlike `PointerWidth`, this is a property of the architecture alone, so
+/// ILP32 environments such as `gnux32` and `gnu_ilp32`, which use 32-bit
+/// pointers on 64-bit architectures, still report `U64` here.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[allow(missing_docs)]
+pub enum Bitness {
+    U16,
+    U32,
+    U64,
+    U128,
+}
+
+impl Bitness {
+    /// Return the number of bits in a native word.
+    pub const fn bits(self) -> u8 {
+        match self {
+            Bitness::U16 => 16,
+            Bitness::U32 => 32,
+            Bitness::U64 => 64,
+            Bitness::U128 => 128,
+        }
+    }
+}
+
 /// The calling convention, which specifies things like which registers are
 /// used for passing arguments, which registers are callee-saved, and so on.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
@@ -70,6 +99,55 @@ pub enum CallingConvention {
     WindowsFastcall,
 }
 
+/// The concurrency primitives a target provides.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Threading {
+    /// The operating system provides threads.
+    Threads,
+    /// Atomic operations are available, but there are no operating system
+    /// threads. Concurrency, if any, comes from interrupts or from the
+    /// embedder, as with WebAssembly's threads proposal.
+    AtomicsOnly,
+    /// There are neither threads nor atomic compare-and-swap operations.
+    Unsupported,
+}
+
+/// A kind of artifact rustc can produce.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CrateType {
+    /// An executable.
+    Bin,
+    /// A Rust library, for linking into other Rust crates.
+    Rlib,
+    /// A dynamic Rust library.
+    Dylib,
+    /// A dynamic library with a C interface.
+    Cdylib,
+    /// A static library with a C interface.
+    Staticlib,
+    /// A procedural macro, which is loaded into the compiler as a dynamic
+    /// library and so can only be built for the host.
+    ProcMacro,
+}
+
+/// The code model, which constrains how far apart code and data may be
+/// placed, and therefore which instruction sequences are used to address them.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CodeModel {
+    /// Code and data are linked within a small, typically 2 GiB, range.
+    Small,
+    /// Code and data live in the top 2 GiB of the address space, as used by
+    /// kernels on x86-64.
+    Kernel,
+    /// Code is small but data may be large.
+    Medium,
+    /// No assumptions are made about where code and data are placed.
+    Large,
+}
+
 /// A target "triple". Historically such things had three fields, though they've
 /// added additional fields over time.
 ///
@@ -93,17 +171,48 @@ pub struct Triple {
 
 impl Triple {
     /// Return the endianness of this target's architecture.
-    pub fn endianness(&self) -> Result<Endianness, ()> {
+    #[allow(clippy::result_unit_err)]
+    pub const fn endianness(&self) -> Result<Endianness, ()> {
         self.architecture.endianness()
     }
 
-    /// Return the pointer width of this target's architecture.
-    pub fn pointer_width(&self) -> Result<PointerWidth, ()> {
-        self.architecture.pointer_width()
+    /// Return the pointer width of this target's architecture, taking into
+    /// account ILP32 environments such as `gnux32` and `gnu_ilp32`, which use
+    /// 32-bit pointers on 64-bit architectures.
+    #[allow(clippy::result_unit_err)]
+    pub const fn pointer_width(&self) -> Result<PointerWidth, ()> {
+        match self.environment {
+            Environment::Gnux32 | Environment::GnuIlp32 => Ok(PointerWidth::U32),
+            _ => self.architecture.pointer_width(),
+        }
+    }
+
+    /// Return the native word size of this target's architecture.
+    #[allow(clippy::result_unit_err)]
+    pub const fn bitness(&self) -> Result<Bitness, ()> {
+        self.architecture.bitness()
+    }
+
+    /// Test whether this target's architecture is 16-bit.
+    pub const fn is_16bit(&self) -> bool {
+        matches!(self.bitness(), Ok(Bitness::U16))
+    }
+
+    /// Test whether this target's architecture is 32-bit.
+    pub const fn is_32bit(&self) -> bool {
+        matches!(self.bitness(), Ok(Bitness::U32))
+    }
+
+    /// Test whether this target's architecture is 64-bit. This is true for
+    /// ILP32 environments on 64-bit architectures too; use `pointer_width`
+    /// to ask about the size of pointers instead.
+    pub const fn is_64bit(&self) -> bool {
+        matches!(self.bitness(), Ok(Bitness::U64))
     }
 
     /// Return the default calling convention for the given target triple.
-    pub fn default_calling_convention(&self) -> Result<CallingConvention, ()> {
+    #[allow(clippy::result_unit_err)]
+    pub const fn default_calling_convention(&self) -> Result<CallingConvention, ()> {
         Ok(match self.operating_system {
             OperatingSystem::Bitrig
             | OperatingSystem::Cloudabi
@@ -117,16 +226,28 @@ impl Triple {
             | OperatingSystem::L4re
             | OperatingSystem::Linux
             | OperatingSystem::MacOSX { .. }
+            | OperatingSystem::Managarm
             | OperatingSystem::Netbsd
             | OperatingSystem::Openbsd
+            | OperatingSystem::Ps4
+            | OperatingSystem::Ps5
             | OperatingSystem::Redox
-            | OperatingSystem::Solaris => CallingConvention::SystemV,
+            | OperatingSystem::Solaris
+            | OperatingSystem::Tvos
+            | OperatingSystem::Watchos => CallingConvention::SystemV,
             OperatingSystem::Windows => CallingConvention::WindowsFastcall,
             OperatingSystem::Nebulet
             | OperatingSystem::Emscripten
             | OperatingSystem::Wasi
+            | OperatingSystem::WasiP2
+            | OperatingSystem::WasiP3
             | OperatingSystem::Unknown => match self.architecture {
                 Architecture::Wasm32 => CallingConvention::WasmBasicCAbi,
+                // SGX enclaves have no operating system, but use the System V
+                // ABI, as in `x86_64-fortanix-unknown-sgx`.
+                Architecture::X86_64 if matches!(self.environment, Environment::Sgx) => {
+                    CallingConvention::SystemV
+                }
                 _ => return Err(()),
             },
             _ => return Err(()),
@@ -134,22 +255,30 @@ impl Triple {
     }
 
     /// The C data model for a given target. If the model is not known, returns `Err(())`.
-    pub fn data_model(&self) -> Result<CDataModel, ()> {
-        match self.pointer_width()? {
-            PointerWidth::U64 => {
-                if self.operating_system == OperatingSystem::Windows {
+    #[allow(clippy::result_unit_err)]
+    pub const fn data_model(&self) -> Result<CDataModel, ()> {
+        match self.pointer_width() {
+            Ok(PointerWidth::U64) => {
+                if matches!(self.operating_system, OperatingSystem::Windows) {
                     Ok(CDataModel::LLP64)
-                } else if self.default_calling_convention() == Ok(CallingConvention::SystemV)
-                    || self.architecture == Architecture::Wasm64 {
+                } else if matches!(
+                    self.default_calling_convention(),
+                    Ok(CallingConvention::SystemV)
+                ) || matches!(self.architecture, Architecture::Wasm64)
+                {
                     Ok(CDataModel::LP64)
                 } else {
                     Err(())
                 }
             }
-            PointerWidth::U32 => {
-                if self.operating_system == OperatingSystem::Windows
-                    || self.default_calling_convention() == Ok(CallingConvention::SystemV)
-                    || self.architecture == Architecture::Wasm32 {
+            Ok(PointerWidth::U32) => {
+                if matches!(self.operating_system, OperatingSystem::Windows)
+                    || matches!(
+                        self.default_calling_convention(),
+                        Ok(CallingConvention::SystemV)
+                    )
+                    || matches!(self.architecture, Architecture::Wasm32)
+                {
                     Ok(CDataModel::ILP32)
                 } else {
                     Err(())
@@ -159,12 +288,729 @@ impl Triple {
             // between near-pointers and far-pointers.
             // Additionally, code pointers sometimes have a different size than data pointers.
             // We don't handle this case.
-            PointerWidth::U16 => Err(()),
+            Ok(PointerWidth::U16) | Err(()) => Err(()),
+        }
+    }
+
+    /// Return the concurrency primitives this target provides.
+    #[allow(clippy::result_unit_err)]
+    pub fn threading(&self) -> Result<Threading, ()> {
+        let has_atomics = match self.architecture {
+            // A bare `riscv32` or `riscv64` doesn't say either way.
+            Architecture::Riscv(riscv) => {
+                riscv.extensions().next().is_none() || riscv.has_extension(RiscvExtension::A)
+            }
+            architecture => !matches!(
+                architecture,
+                Architecture::Arm(ArmArchitecture::Armv4)
+                    | Architecture::Arm(ArmArchitecture::Armv4t)
+                    | Architecture::Arm(ArmArchitecture::Armv5t)
+                    | Architecture::Arm(ArmArchitecture::Armv5te)
+                    | Architecture::Arm(ArmArchitecture::Armv5tej)
+                    | Architecture::Arm(ArmArchitecture::Armv6m)
+                    | Architecture::Arm(ArmArchitecture::Thumbv6m)
+                    | Architecture::Msp430
+            ),
+        };
+
+        match self.operating_system {
+            _ if self.architecture == Architecture::Unknown => Err(()),
+            // SGX enclaves have no operating system, but do have threads.
+            OperatingSystem::Unknown if self.environment == Environment::Sgx => {
+                Ok(Threading::Threads)
+            }
+            OperatingSystem::AmdHsa
+            | OperatingSystem::AmdPal
+            | OperatingSystem::Cuda
+            | OperatingSystem::Emscripten
+            | OperatingSystem::None_
+            | OperatingSystem::Psx
+            | OperatingSystem::Uefi
+            | OperatingSystem::Unknown
+            | OperatingSystem::Wasi
+            | OperatingSystem::WasiP2
+            | OperatingSystem::WasiP3 => Ok(if has_atomics {
+                Threading::AtomicsOnly
+            } else {
+                Threading::Unsupported
+            }),
+            _ => Ok(Threading::Threads),
+        }
+    }
+
+    /// Test whether rustc can produce the given kind of artifact for this
+    /// target, returning the reason if it can't. This considers only what
+    /// the target supports, not whether it's the host, which procedural
+    /// macros additionally require.
+    pub fn supports_crate_type(&self, crate_type: CrateType) -> Result<(), &'static str> {
+        let is_cdylib = match crate_type {
+            CrateType::Bin | CrateType::Rlib | CrateType::Staticlib => return Ok(()),
+            CrateType::Dylib | CrateType::ProcMacro => false,
+            CrateType::Cdylib => true,
+        };
+
+        // WebAssembly and PTX output is always a single module, which rustc
+        // only produces as a `cdylib`.
+        if matches!(
+            self.architecture,
+            Architecture::Asmjs
+                | Architecture::Nvptx64
+                | Architecture::Wasm32
+                | Architecture::Wasm64
+        ) {
+            return if is_cdylib {
+                Ok(())
+            } else {
+                Err("this target only supports cdylib dynamic libraries")
+            };
+        }
+
+        match self.operating_system {
+            // Bare-metal targets, and embedded and console systems which link
+            // programs into a single image.
+            OperatingSystem::Espidf
+            | OperatingSystem::Nintendo3ds
+            | OperatingSystem::None_
+            | OperatingSystem::Nuttx
+            | OperatingSystem::Psp
+            | OperatingSystem::Psx
+            | OperatingSystem::Rtems
+            | OperatingSystem::SolidAsp3
+            | OperatingSystem::Switch
+            | OperatingSystem::Uefi
+            | OperatingSystem::Unknown
+            | OperatingSystem::Vita => {
+                Err("this target has no dynamic linker, so it doesn't support dynamic libraries")
+            }
+            OperatingSystem::Linux
+                if matches!(
+                    self.environment,
+                    Environment::Musl
+                        | Environment::Musleabi
+                        | Environment::Musleabihf
+                        | Environment::Muslabi64
+                ) =>
+            {
+                Err("musl targets link statically by default, so they don't support dynamic libraries")
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Return the default code model for the given target triple.
+    #[allow(clippy::result_unit_err)]
+    pub fn default_code_model(&self) -> Result<CodeModel, ()> {
+        match self.architecture {
+            Architecture::Unknown => Err(()),
+            Architecture::X86_64
+                if self.operating_system == OperatingSystem::Linux
+                    && self.environment == Environment::Kernel =>
+            {
+                Ok(CodeModel::Kernel)
+            }
+            _ => Ok(CodeModel::Small),
+        }
+    }
+
+    /// Test whether the default ABI for the given target triple lets leaf
+    /// functions use the area below the stack pointer (the "red zone") without
+    /// adjusting the stack pointer first. Kernel code can't, since interrupts
+    /// may clobber that area.
+    #[allow(clippy::result_unit_err)]
+    pub fn has_red_zone(&self) -> Result<bool, ()> {
+        if self.environment == Environment::Kernel {
+            return Ok(false);
+        }
+        match self.architecture {
+            Architecture::Unknown => Err(()),
+            Architecture::X86_64 => {
+                Ok(self.default_calling_convention() == Ok(CallingConvention::SystemV))
+            }
+            Architecture::Aarch64(_) => Ok(matches!(
+                self.operating_system,
+                OperatingSystem::Darwin
+                    | OperatingSystem::Ios
+                    | OperatingSystem::MacOSX { .. }
+                    | OperatingSystem::Tvos
+                    | OperatingSystem::Watchos
+            )),
+            Architecture::Powerpc64 | Architecture::Powerpc64le => Ok(true),
+            _ => Ok(false),
+        }
+    }
+
+    /// Test whether code for the given target triple must avoid using
+    /// floating-point and vector registers, as kernel code and `softfloat`
+    /// targets do.
+    pub fn uses_soft_float(&self) -> bool {
+        matches!(
+            self.environment,
+            Environment::Kernel | Environment::Softfloat
+        )
+    }
+
+    /// Test whether this triple targets an Apple platform simulator rather
+    /// than a device. This covers the explicit `sim` environment as well as
+    /// the older x86 iOS, tvOS, and watchOS targets, which only ever ran in
+    /// the simulator.
+    pub fn is_simulator(&self) -> bool {
+        match self.operating_system {
+            OperatingSystem::Ios | OperatingSystem::Tvos | OperatingSystem::Watchos => {
+                self.environment == Environment::Sim
+                    || match self.architecture {
+                        Architecture::X86_32(_) | Architecture::X86_64 => {
+                            self.environment != Environment::MacABI
+                        }
+                        _ => false,
+                    }
+            }
+            _ => false,
+        }
+    }
+
+    /// Test whether this triple targets the Universal Windows Platform, which
+    /// restricts the available Windows APIs.
+    pub fn is_uwp(&self) -> bool {
+        self.vendor == Vendor::Uwp && self.operating_system == OperatingSystem::Windows
+    }
+
+    /// Return the environment of this triple as rustc reports it in
+    /// `target_env`, with the ABI split out; see `target_abi`.
+    pub fn target_env(&self) -> Environment {
+        self.environment.base()
+    }
+
+    /// Return the ABI of this triple as rustc reports it in `target_abi`,
+    /// which may come from the environment, such as `eabihf`, or from the
+    /// vendor, such as `uwp`.
+    pub fn target_abi(&self) -> Option<Abi> {
+        match (&self.vendor, self.environment) {
+            (Vendor::Uwp, _) => Some(Abi::Uwp),
+            (Vendor::Fortanix, Environment::Sgx) => Some(Abi::Fortanix),
+            (_, environment) => environment.abi(),
+        }
+    }
+
+    /// Return the architecture of this triple as rustc reports it in
+    /// `target_arch`. This groups variants together, so all 32-bit Arm
+    /// architectures are `"arm"`, for example.
+    pub fn target_arch_str(&self) -> &'static str {
+        match self.architecture {
+            Architecture::Unknown => "unknown",
+            Architecture::Arm(_) => "arm",
+            Architecture::AmdGcn => "amdgpu",
+            Architecture::Aarch64(_) => "aarch64",
+            Architecture::Asmjs => "wasm32",
+            Architecture::Dxil => "dxil",
+            Architecture::Hexagon => "hexagon",
+            Architecture::X86_32(_) => "x86",
+            Architecture::M68k => "m68k",
+            Architecture::Mips32(Mips32Architecture::Mipsisa32r6)
+            | Architecture::Mips32(Mips32Architecture::Mipsisa32r6el) => "mips32r6",
+            Architecture::Mips32(_) => "mips",
+            Architecture::Mips64(Mips64Architecture::Mipsisa64r6)
+            | Architecture::Mips64(Mips64Architecture::Mipsisa64r6el) => "mips64r6",
+            Architecture::Mips64(_) => "mips64",
+            Architecture::Msp430 => "msp430",
+            Architecture::Nvptx64 => "nvptx64",
+            Architecture::Powerpc => "powerpc",
+            Architecture::Powerpc64 | Architecture::Powerpc64le => "powerpc64",
+            Architecture::Riscv(riscv) => match riscv.base_width() {
+                PointerWidth::U64 => "riscv64",
+                _ => "riscv32",
+            },
+            Architecture::S390x => "s390x",
+            Architecture::Sparc => "sparc",
+            Architecture::Sparc64 | Architecture::Sparcv9 => "sparc64",
+            Architecture::Spirv | Architecture::Spirv32 | Architecture::Spirv64 => "spirv",
+            Architecture::Wasm32 => "wasm32",
+            Architecture::Wasm64 => "wasm64",
+            Architecture::X86_64 => "x86_64",
+            Architecture::Xtensa => "xtensa",
+        }
+    }
+
+    /// Return the vendor of this triple as rustc reports it in
+    /// `target_vendor`. All Espressif vendors are `"espressif"`.
+    pub fn target_vendor_str(&self) -> &str {
+        match self.vendor {
+            Vendor::Espressif(_) => "espressif",
+            _ => self.vendor.as_str(),
+        }
+    }
+
+    /// Return the operating system of this triple as rustc reports it in
+    /// `target_os`. This differs from the `Display` form in places; macOS is
+    /// `"macos"` whatever its version, and Android is `"android"` rather than
+    /// `"linux"`.
+    pub fn target_os_str(&self) -> &'static str {
+        match self.operating_system {
+            OperatingSystem::Unknown => "unknown",
+            OperatingSystem::Aix => "aix",
+            OperatingSystem::AmdHsa => "amdhsa",
+            OperatingSystem::AmdPal => "amdpal",
+            OperatingSystem::Bitrig => "bitrig",
+            OperatingSystem::Cloudabi => "cloudabi",
+            OperatingSystem::Cuda => "cuda",
+            OperatingSystem::Darwin | OperatingSystem::MacOSX { .. } => "macos",
+            OperatingSystem::Dragonfly => "dragonfly",
+            OperatingSystem::Emscripten => "emscripten",
+            OperatingSystem::Espidf => "espidf",
+            OperatingSystem::Freebsd => "freebsd",
+            OperatingSystem::Fuchsia => "fuchsia",
+            OperatingSystem::Haiku => "haiku",
+            OperatingSystem::Hermit => "hermit",
+            OperatingSystem::Ios => "ios",
+            OperatingSystem::L4re => "l4re",
+            OperatingSystem::Linux => match self.environment {
+                Environment::Android | Environment::Androideabi => "android",
+                _ => "linux",
+            },
+            OperatingSystem::Managarm => "managarm",
+            OperatingSystem::Nebulet => "nebulet",
+            OperatingSystem::Netbsd => "netbsd",
+            OperatingSystem::Nintendo3ds | OperatingSystem::Switch => "horizon",
+            OperatingSystem::None_ | OperatingSystem::Psx => "none",
+            OperatingSystem::Nuttx => "nuttx",
+            OperatingSystem::Openbsd => "openbsd",
+            OperatingSystem::Ps4 => "ps4",
+            OperatingSystem::Ps5 => "ps5",
+            OperatingSystem::Psp => "psp",
+            OperatingSystem::Redox => "redox",
+            OperatingSystem::Rtems => "rtems",
+            OperatingSystem::ShaderModel { .. } => "shadermodel",
+            OperatingSystem::Solaris => "solaris",
+            OperatingSystem::SolidAsp3 => "solid_asp3",
+            OperatingSystem::Tvos => "tvos",
+            OperatingSystem::Uefi => "uefi",
+            OperatingSystem::Vita => "vita",
+            OperatingSystem::VxWorks => "vxworks",
+            OperatingSystem::Wasi | OperatingSystem::WasiP2 | OperatingSystem::WasiP3 => "wasi",
+            OperatingSystem::Watchos => "watchos",
+            OperatingSystem::Windows => "windows",
+            OperatingSystem::Zos => "zos",
+        }
+    }
+
+    /// Return the environment of this triple as rustc reports it in
+    /// `target_env`, which is the empty string for targets without one.
+    /// ESP-IDF targets are `"newlib"`, which they're built against, and WASI
+    /// targets name their preview, as in `"p1"` for `wasm32-wasip1`.
+    pub fn target_env_str(&self) -> &'static str {
+        match self.operating_system {
+            OperatingSystem::Espidf => return "newlib",
+            OperatingSystem::Wasi => return "p1",
+            OperatingSystem::WasiP2 => return "p2",
+            OperatingSystem::WasiP3 => return "p3",
+            _ => {}
+        }
+        match self.target_env() {
+            Environment::Gnu => "gnu",
+            Environment::Mlibc => "mlibc",
+            Environment::Msvc => "msvc",
+            Environment::Musl => "musl",
+            Environment::Newlib => "newlib",
+            Environment::Relibc => "relibc",
+            Environment::Sgx => "sgx",
+            Environment::Uclibc => "uclibc",
+            _ => "",
+        }
+    }
+
+    /// Return the triple LLDB expects for this target, such as in its
+    /// `target create --arch` and `platform` commands. This always includes
+    /// the vendor, calls 64-bit Arm on Apple platforms `arm64`, and calls
+    /// `darwin` `macosx`.
+    pub fn lldb_triple(&self) -> String {
+        let mut lldb = String::new();
+        match (&self.architecture, &self.vendor) {
+            (Architecture::Aarch64(_), Vendor::Apple) => lldb.push_str("arm64"),
+            (architecture, _) => lldb.push_str(&architecture.to_string()),
+        }
+        lldb.push('-');
+        lldb.push_str(self.vendor.as_str());
+        lldb.push('-');
+        match self.operating_system {
+            OperatingSystem::Darwin => lldb.push_str("macosx"),
+            operating_system => lldb.push_str(&operating_system.to_string()),
+        }
+        if self.environment != Environment::Unknown {
+            lldb.push('-');
+            lldb.push_str(&self.environment.to_string());
+        }
+        lldb
+    }
+
+    /// Return the name rustup and rustc use for this target, as accepted by
+    /// `rustup target add` and `rustc --target`.
+    ///
+    /// Details that rustc target names don't carry, such as macOS versions
+    /// and MSVC toolsets, are dropped, and targets which have been renamed
+    /// get their current names, such as `wasm32-wasip1` for `wasm32-wasi` and
+    /// `x86_64-pc-solaris` for `x86_64-sun-solaris`. The name is spelled as
+    /// rustc spells it, which isn't always the `Display` form; rustc keeps
+    /// the vendor in `x86_64-unknown-fuchsia` and the format in
+    /// `riscv32imc-unknown-nuttx-elf`, but drops the vendor in
+    /// `armv4t-none-eabi`.
+    pub fn to_rustup_target(&self) -> String {
+        let operating_system = match self.operating_system {
+            OperatingSystem::MacOSX { .. } => OperatingSystem::Darwin,
+            operating_system => operating_system,
+        };
+        let environment = match self.environment {
+            Environment::MsvcVersioned(_) => Environment::Msvc,
+            Environment::GnuMsvcrt | Environment::GnuUcrt => Environment::Gnu,
+            environment => environment,
+        };
+
+        let mut name = self.architecture.to_string();
+
+        // rustc omits an unknown vendor for Android, WASI, and 32-bit Arm and
+        // MSP430 targets without a full operating system, but not for
+        // Fuchsia or other bare-metal targets.
+        let omit_vendor = self.vendor == Vendor::Unknown
+            && match operating_system {
+                OperatingSystem::Linux => {
+                    matches!(environment, Environment::Android | Environment::Androideabi)
+                }
+                OperatingSystem::Wasi | OperatingSystem::WasiP2 | OperatingSystem::WasiP3 => true,
+                OperatingSystem::None_ | OperatingSystem::Nuttx | OperatingSystem::Rtems => {
+                    matches!(
+                        self.architecture,
+                        Architecture::Arm(_) | Architecture::Msp430
+                    )
+                }
+                _ => false,
+            };
+        if !omit_vendor {
+            name.push('-');
+            name.push_str(self.vendor_str());
+        }
+
+        name.push('-');
+        name.push_str(&operating_system.to_string());
+        if environment != Environment::Unknown {
+            name.push('-');
+            name.push_str(&environment.to_string());
+        }
+
+        // rustc spells out the ELF format of bare-metal targets without an
+        // environment, and of its RISC-V NuttX targets.
+        let elf = self.binary_format == BinaryFormat::Elf
+            && environment == Environment::Unknown
+            && match operating_system {
+                OperatingSystem::None_ => true,
+                OperatingSystem::Nuttx => matches!(self.architecture, Architecture::Riscv(_)),
+                _ => false,
+            };
+        if elf {
+            name.push_str("-elf");
+        }
+
+        match RUSTUP_RENAMES.iter().find(|(_, triple)| *triple == name) {
+            Some((renamed, _)) => (*renamed).to_owned(),
+            None => name,
+        }
+    }
+
+    /// Parse a rustup or rustc target name, accepting names targets had
+    /// before being renamed, such as `wasm32-wasi`, and aliases such as
+    /// `arm64-apple-darwin`, as well as their current ones. This is the
+    /// inverse of `to_rustup_target`.
+    ///
+    /// Targets which differ from another only in enabling threads, such as
+    /// `wasm32-wasip1-threads`, are rejected, since a `Triple` can't record
+    /// that.
+    pub fn from_rustup_target(name: &str) -> Result<Self, ParseError> {
+        if name.ends_with("-threads") {
+            return Err(ParseError::UnrecognizedEnvironment("threads".to_owned()));
+        }
+        match RUSTUP_RENAMES.iter().find(|(renamed, _)| *renamed == name) {
+            Some((_, triple)) => Self::from_str(triple),
+            None => Self::from_str(name),
+        }
+    }
+
+    /// Return this triple with its architecture replaced.
+    ///
+    /// As with the other `with_` methods, if the binary format was the one
+    /// implied by the other fields, it's replaced by the format implied by the
+    /// new ones; an explicitly chosen format is kept.
+    pub fn with_architecture(self, architecture: Architecture) -> Self {
+        self.with_implied_binary_format(|triple| triple.architecture = architecture)
+    }
+
+    /// Return this triple with its vendor replaced.
+    pub fn with_vendor(self, vendor: Vendor) -> Self {
+        self.with_implied_binary_format(|triple| triple.vendor = vendor)
+    }
+
+    /// Return this triple with its operating system replaced.
+    pub fn with_operating_system(self, operating_system: OperatingSystem) -> Self {
+        self.with_implied_binary_format(|triple| triple.operating_system = operating_system)
+    }
+
+    /// Return this triple with its environment replaced, such as to get the
+    /// musl or simulator variant of a target.
+    pub fn with_environment(self, environment: Environment) -> Self {
+        self.with_implied_binary_format(|triple| triple.environment = environment)
+    }
+
+    fn with_implied_binary_format<F: FnOnce(&mut Self)>(mut self, f: F) -> Self {
+        let implied = self.binary_format == default_binary_format(&self);
+        f(&mut self);
+        if implied {
+            self.binary_format = default_binary_format(&self);
+        }
+        self
+    }
+
+    /// Return this triple with its binary format replaced, such as to describe
+    /// the final flash image produced for a bare-metal target.
+    pub fn with_binary_format(mut self, binary_format: BinaryFormat) -> Self {
+        self.binary_format = binary_format;
+        self
+    }
+
+    /// Return a hash of this triple suitable for cache keys and artifact
+    /// directory names.
+    ///
+    /// Unlike the `Hash` implementation, whose output may change between
+    /// releases of this crate or of Rust, this is guaranteed to be the 64-bit
+    /// FNV-1a hash of the triple's canonical string form (its `Display`
+    /// output). It therefore only changes if that string does.
+    pub fn stable_hash(&self) -> u64 {
+        struct Fnv1a(u64);
+
+        impl fmt::Write for Fnv1a {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                for byte in s.bytes() {
+                    self.0 ^= u64::from(byte);
+                    self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+                }
+                Ok(())
+            }
+        }
+
+        let mut hasher = Fnv1a(0xcbf2_9ce4_8422_2325);
+        fmt::write(&mut hasher, format_args!("{}", self)).unwrap();
+        hasher.0
+    }
+
+    /// Parse a triple which may have more components than `from_str`
+    /// accepts, such as the repeated `unknown` fillers in
+    /// `wasm32-unknown-unknown-unknown` or a trailing component appended by
+    /// an SDK or build system.
+    ///
+    /// The longest prefix that parses is used as the triple. The remaining
+    /// components, along with any trailing fillers that the triple's
+    /// `Display` form omits, are kept in the `extra` field of the result. The
+    /// prefix is kept as it was spelled, so the result displays as the
+    /// original string even where the triple's own `Display` form differs.
+    pub fn from_str_tolerant(s: &str) -> Result<ExtendedTriple, ParseError> {
+        let parts: Vec<&str> = s.split('-').collect();
+        let prefix = |consumed: usize| {
+            let len = parts[..consumed]
+                .iter()
+                .map(|part| part.len())
+                .sum::<usize>()
+                + consumed
+                - 1;
+            &s[..len]
+        };
+        let mut consumed = parts.len();
+        let mut triple = loop {
+            match Self::from_str(prefix(consumed)) {
+                Ok(triple) => break triple,
+                Err(err) if consumed == 1 => return Err(err),
+                Err(_) => consumed -= 1,
+            }
+        };
+
+        // A trailing `unknown` parses as `BinaryFormat::Unknown`, but in a
+        // longer string it's more likely to be a filler than a statement
+        // that the format is unknown, so keep the implied format instead.
+        if consumed > 1
+            && parts[consumed - 1] == "unknown"
+            && triple.binary_format == BinaryFormat::Unknown
+        {
+            if let Ok(shorter) = Self::from_str(prefix(consumed - 1)) {
+                triple = shorter;
+                consumed -= 1;
+            }
+        }
+
+        // Trailing components which carry no information, such as a
+        // redundant `unknown`, are dropped from the `Display` form. If that
+        // form is still a prefix of the input, treat them as extras as well.
+        let displayed = triple.to_string();
+        let shown = displayed.split('-').count();
+        if shown < consumed && parts[..shown].join("-") == displayed {
+            consumed = shown;
+        }
+
+        Ok(ExtendedTriple {
+            triple,
+            prefix: prefix(consumed).to_owned(),
+            extra: parts[consumed..]
+                .iter()
+                .map(|part| (*part).to_owned())
+                .collect(),
+        })
+    }
+
+    /// Parse an abbreviated triple, such as `x86_64-linux`, `aarch64-macos`,
+    /// or `wasm32`, filling in the fields it omits with their most common
+    /// values:
+    ///
+    ///  - `macos` is accepted as a name for `darwin`.
+    ///  - Apple operating systems imply the `apple` vendor.
+    ///  - `windows` implies the `pc` vendor and, if no environment is given,
+    ///    `msvc`.
+    ///  - `linux` with no environment implies `gnu`, or `gnueabihf` on 32-bit
+    ///    Arm.
+    ///  - `android` implies `linux`, and becomes `androideabi` on 32-bit Arm.
+    ///  - A bare architecture implies the `unknown` vendor and operating
+    ///    system.
+    ///
+    /// Fields that are given explicitly are never changed, so a full triple
+    /// expands to itself.
+    pub fn expand(s: &str) -> Result<Self, ParseError> {
+        let parts: Vec<&str> = s
+            .split('-')
+            .map(|part| if part == "macos" { "darwin" } else { part })
+            .collect();
+        let mut triple = Self::from_str(&parts.join("-"))?;
+        let is_arm32 = matches!(triple.architecture, Architecture::Arm(_));
+
+        if triple.operating_system == OperatingSystem::Unknown
+            && matches!(
+                triple.environment,
+                Environment::Android | Environment::Androideabi
+            )
+        {
+            triple.operating_system = OperatingSystem::Linux;
+            if is_arm32 {
+                triple.environment = Environment::Androideabi;
+            }
+        }
+
+        match triple.operating_system {
+            OperatingSystem::Darwin
+            | OperatingSystem::Ios
+            | OperatingSystem::MacOSX { .. }
+            | OperatingSystem::Tvos
+            | OperatingSystem::Watchos
+                if triple.vendor == Vendor::Unknown =>
+            {
+                triple.vendor = Vendor::Apple;
+            }
+            OperatingSystem::Linux if triple.environment == Environment::Unknown => {
+                triple.environment = if is_arm32 {
+                    Environment::Gnueabihf
+                } else {
+                    Environment::Gnu
+                };
+            }
+            OperatingSystem::Windows => {
+                if triple.vendor == Vendor::Unknown {
+                    triple.vendor = Vendor::Pc;
+                }
+                if triple.environment == Environment::Unknown {
+                    triple.environment = Environment::Msvc;
+                }
+            }
+            _ => {}
+        }
+
+        if triple.binary_format == BinaryFormat::Unknown {
+            triple.binary_format = default_binary_format(&triple);
+        }
+        Ok(triple)
+    }
+
+    /// Return a form of this triple consisting only of ASCII lowercase
+    /// letters, digits, and underscores, so that it can be used in file
+    /// names, directory names, and Rust identifiers.
+    ///
+    /// Each `-` becomes `__` and each `.` becomes `_p`, so that for example
+    /// `x86_64-apple-macosx10.7.0` becomes `x86_64__apple__macosx10_p7_p0`.
+    /// An underscore which could otherwise be misread is written as `_u`.
+    /// `from_slug` reverses this.
+    pub fn to_slug(&self) -> String {
+        let s = self.to_string();
+        let mut slug = String::with_capacity(s.len());
+        let mut chars = s.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '-' => slug.push_str("__"),
+                '.' => slug.push_str("_p"),
+                '_' => match chars.peek() {
+                    Some(next) if next.is_ascii_alphanumeric() && !matches!(next, 'p' | 'u') => {
+                        slug.push('_')
+                    }
+                    _ => slug.push_str("_u"),
+                },
+                c => slug.push(c),
+            }
+        }
+        slug
+    }
+
+    /// Parse a triple from the form produced by `to_slug`.
+    pub fn from_slug(slug: &str) -> Result<Self, ParseError> {
+        let mut s = String::with_capacity(slug.len());
+        let mut chars = slug.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c != '_' {
+                s.push(c);
+                continue;
+            }
+            match chars.peek() {
+                Some('_') => s.push('-'),
+                Some('p') => s.push('.'),
+                Some('u') => s.push('_'),
+                _ => {
+                    s.push('_');
+                    continue;
+                }
+            }
+            chars.next();
+        }
+        Self::from_str(&s)
+    }
+
+    /// Return the differences between this triple and `other`, such as to
+    /// explain why an artifact built for one can't be used for the other.
+    pub fn diff<'a>(&'a self, other: &'a Triple) -> TripleDiff<'a> {
+        TripleDiff { this: self, other }
+    }
+
+    /// Parse a triple in a `const` context, as `from_str` would.
+    ///
+    /// A custom vendor is stored as a `CustomVendor::Static` borrowing from
+    /// `s`. This returns an `Option` rather than a `Result` because
+    /// `Option::expect` can be called in `const` contexts, so a triple that
+    /// doesn't parse can be made a compile error; the `triple!` macro does
+    /// exactly that.
+    pub const fn from_str_const(s: &'static str) -> Option<Self> {
+        match parse(s) {
+            Ok(parts) => Some(Self {
+                architecture: parts.architecture,
+                vendor: match parts.vendor {
+                    Some(name) => Vendor::from_static_str(name),
+                    None => Vendor::Unknown,
+                },
+                operating_system: parts.operating_system,
+                environment: parts.environment,
+                binary_format: parts.binary_format,
+            }),
+            Err(_) => None,
         }
     }
 
     /// Return a `Triple` with all unknown fields.
-    pub fn unknown() -> Self {
+    pub const fn unknown() -> Self {
         Self {
             architecture: Architecture::Unknown,
             vendor: Vendor::Unknown,
@@ -175,9 +1021,21 @@ impl Triple {
     }
 }
 
+impl Triple {
+    /// Return the vendor as it's spelled in this triple. `Vendor::Sony` is
+    /// spelled `scei` on the PS4 and `sie` on the PS5, as rustc and LLVM do.
+    pub(crate) fn vendor_str(&self) -> &str {
+        match (&self.vendor, self.operating_system) {
+            (Vendor::Sony, OperatingSystem::Ps4) => "scei",
+            (Vendor::Sony, OperatingSystem::Ps5) => "sie",
+            (vendor, _) => vendor.as_str(),
+        }
+    }
+}
+
 impl fmt::Display for Triple {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let implied_binary_format = default_binary_format(&self);
+        let implied_binary_format = default_binary_format(self);
 
         write!(f, "{}", self.architecture)?;
         if self.vendor == Vendor::Unknown
@@ -187,6 +1045,8 @@ impl fmt::Display for Triple {
                     || self.environment == Environment::Kernel))
                 || self.operating_system == OperatingSystem::Fuchsia
                 || self.operating_system == OperatingSystem::Wasi
+                || self.operating_system == OperatingSystem::WasiP2
+                || self.operating_system == OperatingSystem::WasiP3
                 || (self.operating_system == OperatingSystem::None_
                     && (self.architecture == Architecture::Arm(ArmArchitecture::Armebv7r)
                         || self.architecture == Architecture::Arm(ArmArchitecture::Armv7a)
@@ -205,7 +1065,7 @@ impl fmt::Display for Triple {
             // triples.
             write!(f, "-{}", self.operating_system)?;
         } else {
-            write!(f, "-{}-{}", self.vendor, self.operating_system)?;
+            write!(f, "-{}-{}", self.vendor_str(), self.operating_system)?;
         }
         if self.environment != Environment::Unknown {
             write!(f, "-{}", self.environment)?;
@@ -218,101 +1078,324 @@ impl fmt::Display for Triple {
     }
 }
 
-impl FromStr for Triple {
-    type Err = ParseError;
+/// The fields of a triple as parsed by `parse`. The vendor is left as its
+/// name, so that callers can store a custom vendor as they see fit.
+struct Parts<'a> {
+    architecture: Architecture,
+    vendor: Option<&'a str>,
+    operating_system: OperatingSystem,
+    environment: Environment,
+    binary_format: BinaryFormat,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split('-');
-        let mut result = Self::unknown();
-        let mut current_part;
-
-        current_part = parts.next();
-        if let Some(s) = current_part {
-            if let Ok(architecture) = Architecture::from_str(s) {
-                result.architecture = architecture;
-                current_part = parts.next();
-            } else {
-                // Insist that the triple start with a valid architecture.
-                return Err(ParseError::UnrecognizedArchitecture(s.to_owned()));
-            }
-        }
+/// The field `parse` failed at.
+enum Unrecognized {
+    Architecture,
+    Vendor,
+    OperatingSystem,
+    Environment,
+    BinaryFormat,
+    Field,
+}
 
-        let mut has_vendor = false;
-        let mut has_operating_system = false;
-        if let Some(s) = current_part {
-            if let Ok(vendor) = Vendor::from_str(s) {
-                has_vendor = true;
-                result.vendor = vendor;
-                current_part = parts.next();
-            }
+/// Targets whose rustc names aren't the names `to_rustup_target` would
+/// otherwise produce, as pairs of the rustc name and the name of the triple
+/// it's parsed as.
+const RUSTUP_RENAMES: &[(&str, &str)] = &[
+    ("wasm32-wasip1", "wasm32-wasi"),
+    ("x86_64-pc-solaris", "x86_64-sun-solaris"),
+];
+
+/// Split off the part of a triple up to the next `-`.
+const fn next_part(s: &str) -> (&str, Option<&str>) {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'-' {
+            let (part, rest) = s.split_at(i);
+            return (part, Some(rest.split_at(1).1));
         }
+        i += 1;
+    }
+    (s, None)
+}
 
-        if !has_operating_system {
-            if let Some(s) = current_part {
-                if let Ok(operating_system) = OperatingSystem::from_str(s) {
-                    has_operating_system = true;
-                    result.operating_system = operating_system;
-                    current_part = parts.next();
-                }
-            }
+/// The implementation of `Triple::from_str` and `Triple::from_str_const`.
+const fn parse(s: &str) -> Result<Parts<'_>, (Unrecognized, &str)> {
+    let mut vendor = None;
+    let mut operating_system = OperatingSystem::Unknown;
+    let mut environment = Environment::Unknown;
+    let mut binary_format = BinaryFormat::Unknown;
+
+    let (part, mut rest) = next_part(s);
+    let architecture = match Architecture::from_bytes(part.as_bytes()) {
+        Ok(architecture) => architecture,
+        // Insist that the triple start with a valid architecture.
+        Err(()) => return Err((Unrecognized::Architecture, part)),
+    };
+
+    let mut has_vendor = false;
+    let mut has_operating_system = false;
+    if let Some(s) = rest {
+        let (part, next) = next_part(s);
+        if Vendor::is_valid(part.as_bytes()) {
+            has_vendor = true;
+            vendor = Some(part);
+            rest = next;
         }
+    }
 
-        let mut has_environment = false;
-        if let Some(s) = current_part {
-            if let Ok(environment) = Environment::from_str(s) {
-                has_environment = true;
-                result.environment = environment;
-                current_part = parts.next();
-            }
+    if let Some(s) = rest {
+        let (part, next) = next_part(s);
+        if let Ok(parsed) = OperatingSystem::from_bytes(part.as_bytes()) {
+            has_operating_system = true;
+            operating_system = parsed;
+            rest = next;
         }
+    }
 
-        let mut has_binary_format = false;
-        if let Some(s) = current_part {
-            if let Ok(binary_format) = BinaryFormat::from_str(s) {
-                has_binary_format = true;
-                result.binary_format = binary_format;
-                current_part = parts.next();
-            }
+    let mut has_environment = false;
+    if let Some(s) = rest {
+        let (part, next) = next_part(s);
+        if let Ok(parsed) = Environment::from_bytes(part.as_bytes()) {
+            has_environment = true;
+            environment = parsed;
+            rest = next;
         }
+    }
 
-        // The binary format is frequently omitted; if that's the case here,
-        // infer it from the other fields.
-        if !has_binary_format {
-            result.binary_format = default_binary_format(&result);
+    let mut has_binary_format = false;
+    if let Some(s) = rest {
+        let (part, next) = next_part(s);
+        if let Ok(parsed) = BinaryFormat::from_bytes(part.as_bytes()) {
+            has_binary_format = true;
+            binary_format = parsed;
+            rest = next;
         }
+    }
 
-        if let Some(s) = current_part {
-            Err(
-                if !has_vendor && !has_operating_system && !has_environment && !has_binary_format {
-                    ParseError::UnrecognizedVendor(s.to_owned())
-                } else if !has_operating_system && !has_environment && !has_binary_format {
-                    ParseError::UnrecognizedOperatingSystem(s.to_owned())
-                } else if !has_environment && !has_binary_format {
-                    ParseError::UnrecognizedEnvironment(s.to_owned())
-                } else if !has_binary_format {
-                    ParseError::UnrecognizedBinaryFormat(s.to_owned())
-                } else {
-                    ParseError::UnrecognizedField(s.to_owned())
+    // Bare-metal triples are often written with just a trailing `elf`,
+    // as in `m68k-elf` or `riscv32imac-unknown-elf`; treat a missing
+    // operating system in that case as `none`.
+    if !has_operating_system && has_binary_format && matches!(binary_format, BinaryFormat::Elf) {
+        operating_system = OperatingSystem::None_;
+    }
+
+    // The binary format is frequently omitted; if that's the case here,
+    // infer it from the other fields. The vendor doesn't affect it.
+    if !has_binary_format {
+        binary_format = default_binary_format(&Triple {
+            architecture,
+            vendor: Vendor::Unknown,
+            operating_system,
+            environment,
+            binary_format,
+        });
+    }
+
+    if let Some(s) = rest {
+        Err((
+            if !has_vendor && !has_operating_system && !has_environment && !has_binary_format {
+                Unrecognized::Vendor
+            } else if !has_operating_system && !has_environment && !has_binary_format {
+                Unrecognized::OperatingSystem
+            } else if !has_environment && !has_binary_format {
+                Unrecognized::Environment
+            } else if !has_binary_format {
+                Unrecognized::BinaryFormat
+            } else {
+                Unrecognized::Field
+            },
+            next_part(s).0,
+        ))
+    } else {
+        Ok(Parts {
+            architecture,
+            vendor,
+            operating_system,
+            environment,
+            binary_format,
+        })
+    }
+}
+
+impl FromStr for Triple {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse(s) {
+            Ok(parts) => Ok(Self {
+                architecture: parts.architecture,
+                vendor: match parts.vendor {
+                    Some(name) => Vendor::from_str(name).unwrap(),
+                    None => Vendor::Unknown,
                 },
-            )
-        } else {
-            Ok(result)
+                operating_system: parts.operating_system,
+                environment: parts.environment,
+                binary_format: parts.binary_format,
+            }),
+            Err((unrecognized, s)) => {
+                let s = s.to_owned();
+                Err(match unrecognized {
+                    Unrecognized::Architecture => ParseError::UnrecognizedArchitecture(s),
+                    Unrecognized::Vendor => ParseError::UnrecognizedVendor(s),
+                    Unrecognized::OperatingSystem => ParseError::UnrecognizedOperatingSystem(s),
+                    Unrecognized::Environment => ParseError::UnrecognizedEnvironment(s),
+                    Unrecognized::BinaryFormat => ParseError::UnrecognizedBinaryFormat(s),
+                    Unrecognized::Field => ParseError::UnrecognizedField(s),
+                })
+            }
         }
     }
 }
 
 /// A convenient syntax for triple literals.
 ///
-/// This currently expands to code that just calls `Triple::from_str` and does
-/// an `expect`, though in the future it would be cool to use procedural macros
-/// or so to report errors at compile time instead.
+/// A string literal is parsed with `Triple::from_str_const` at compile time,
+/// so one that doesn't parse is a compile error. Any other `&str` expression
+/// is parsed at runtime, panicking if it doesn't parse.
 #[macro_export]
 macro_rules! triple {
-    ($str:tt) => {
-        target_lexicon::Triple::from_str($str).expect("invalid triple literal")
+    ($str:literal) => {{
+        const TRIPLE: $crate::Triple =
+            $crate::Triple::from_str_const($str).expect("invalid triple literal");
+        TRIPLE
+    }};
+    ($str:expr) => {
+        <$crate::Triple as ::core::str::FromStr>::from_str($str).expect("invalid triple literal")
     };
 }
 
+/// A component of a `Triple`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum TripleComponent {
+    Architecture,
+    Vendor,
+    OperatingSystem,
+    Environment,
+    BinaryFormat,
+}
+
+impl fmt::Display for TripleComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match *self {
+            TripleComponent::Architecture => "architecture",
+            TripleComponent::Vendor => "vendor",
+            TripleComponent::OperatingSystem => "operating system",
+            TripleComponent::Environment => "environment",
+            TripleComponent::BinaryFormat => "binary format",
+        };
+        f.write_str(s)
+    }
+}
+
+/// The differences between two triples, as returned by `Triple::diff`. The
+/// `Display` form explains them, such as `architecture differs (x86_64 vs
+/// aarch64)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TripleDiff<'a> {
+    this: &'a Triple,
+    other: &'a Triple,
+}
+
+impl<'a> TripleDiff<'a> {
+    /// Return the components which differ, in the order they appear in a
+    /// triple.
+    pub fn components(&self) -> Vec<TripleComponent> {
+        let mut components = Vec::new();
+        if self.this.architecture != self.other.architecture {
+            components.push(TripleComponent::Architecture);
+        }
+        if self.this.vendor != self.other.vendor {
+            components.push(TripleComponent::Vendor);
+        }
+        if self.this.operating_system != self.other.operating_system {
+            components.push(TripleComponent::OperatingSystem);
+        }
+        if self.this.environment != self.other.environment {
+            components.push(TripleComponent::Environment);
+        }
+        if self.this.binary_format != self.other.binary_format {
+            components.push(TripleComponent::BinaryFormat);
+        }
+        components
+    }
+
+    /// Test whether the triples are the same.
+    pub fn is_empty(&self) -> bool {
+        self.this == self.other
+    }
+}
+
+impl<'a> fmt::Display for TripleDiff<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let components = self.components();
+        if components.is_empty() {
+            return f.write_str("the triples are the same");
+        }
+        for (i, component) in components.iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{} differs (", component)?;
+            match component {
+                TripleComponent::Architecture => {
+                    write!(
+                        f,
+                        "{} vs {}",
+                        self.this.architecture, self.other.architecture
+                    )
+                }
+                TripleComponent::Vendor => {
+                    write!(f, "{} vs {}", self.this.vendor, self.other.vendor)
+                }
+                TripleComponent::OperatingSystem => write!(
+                    f,
+                    "{} vs {}",
+                    self.this.operating_system, self.other.operating_system
+                ),
+                TripleComponent::Environment => {
+                    write!(f, "{} vs {}", self.this.environment, self.other.environment)
+                }
+                TripleComponent::BinaryFormat => {
+                    write!(
+                        f,
+                        "{} vs {}",
+                        self.this.binary_format, self.other.binary_format
+                    )
+                }
+            }?;
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+/// A triple along with trailing components that aren't part of it, as
+/// returned by `Triple::from_str_tolerant`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ExtendedTriple {
+    /// The recognized triple.
+    pub triple: Triple,
+
+    /// The components that were parsed as `triple`, as they were spelled.
+    pub prefix: String,
+
+    /// The components following the triple, in order.
+    pub extra: Vec<String>,
+}
+
+impl fmt::Display for ExtendedTriple {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.prefix)?;
+        for part in &self.extra {
+            write!(f, "-{}", part)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,4 +1450,664 @@ mod tests {
         assert_eq!(Triple::unknown().pointer_width(), Err(()));
         assert_eq!(Triple::unknown().default_calling_convention(), Err(()));
     }
+
+    #[test]
+    fn ilp32_environments() {
+        let t = Triple::from_str("aarch64-unknown-linux-gnu_ilp32").unwrap();
+        assert_eq!(t.environment, Environment::GnuIlp32);
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U32));
+        assert_eq!(t.data_model(), Ok(CDataModel::ILP32));
+
+        let t = Triple::from_str("x86_64-unknown-linux-gnux32").unwrap();
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U32));
+        assert_eq!(t.data_model(), Ok(CDataModel::ILP32));
+
+        let t = Triple::from_str("aarch64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U64));
+        assert_eq!(t.data_model(), Ok(CDataModel::LP64));
+    }
+
+    #[test]
+    fn bitness() {
+        let t = Triple::from_str("x86_64-unknown-linux-gnux32").unwrap();
+        assert_eq!(t.bitness(), Ok(Bitness::U64));
+        assert!(t.is_64bit());
+        assert_eq!(t.pointer_width(), Ok(PointerWidth::U32));
+
+        let t = Triple::from_str("msp430-none-elf").unwrap();
+        assert!(t.is_16bit());
+        assert!(!t.is_64bit());
+
+        assert!(Triple::from_str("i686-unknown-linux-gnu")
+            .unwrap()
+            .is_32bit());
+        assert_eq!(Triple::unknown().bitness(), Err(()));
+        assert!(!Triple::unknown().is_64bit());
+    }
+
+    #[test]
+    fn kernel_metadata() {
+        let t = Triple::from_str("x86_64-linux-kernel").unwrap();
+        assert_eq!(t.default_code_model(), Ok(CodeModel::Kernel));
+        assert_eq!(t.has_red_zone(), Ok(false));
+        assert!(t.uses_soft_float());
+
+        let t = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(t.default_code_model(), Ok(CodeModel::Small));
+        assert_eq!(t.has_red_zone(), Ok(true));
+        assert!(!t.uses_soft_float());
+
+        assert_eq!(
+            Triple::from_str("x86_64-pc-windows-msvc")
+                .unwrap()
+                .has_red_zone(),
+            Ok(false)
+        );
+        assert_eq!(Triple::unknown().default_code_model(), Err(()));
+    }
+
+    #[test]
+    fn stable_hash() {
+        // These values must never change.
+        assert_eq!(
+            Triple::from_str("x86_64-unknown-linux-gnu")
+                .unwrap()
+                .stable_hash(),
+            0x46b4_0720_c5ac_ab19
+        );
+        assert_eq!(
+            Triple::from_str("x86_64-unknown-linux-gnu-elf")
+                .unwrap()
+                .stable_hash(),
+            0x46b4_0720_c5ac_ab19
+        );
+        assert_ne!(
+            Triple::from_str("x86_64-unknown-linux-musl")
+                .unwrap()
+                .stable_hash(),
+            0x46b4_0720_c5ac_ab19
+        );
+    }
+
+    #[test]
+    fn binary_format_override() {
+        let t = Triple::from_str("thumbv7em-none-eabihf").unwrap();
+        assert_eq!(t.binary_format, BinaryFormat::Elf);
+        let t = t.with_binary_format(BinaryFormat::Ihex);
+        assert_eq!(t.binary_format, BinaryFormat::Ihex);
+        assert_eq!(t.to_string(), "thumbv7em-none-eabihf-ihex");
+        assert_eq!(Triple::from_str("thumbv7em-none-eabihf-ihex"), Ok(t));
+    }
+
+    #[test]
+    fn uwp() {
+        assert!(Triple::from_str("x86_64-uwp-windows-msvc")
+            .unwrap()
+            .is_uwp());
+        assert!(Triple::from_str("i686-uwp-windows-gnu").unwrap().is_uwp());
+        assert!(!Triple::from_str("x86_64-pc-windows-msvc").unwrap().is_uwp());
+    }
+
+    #[test]
+    fn simulators() {
+        assert!(Triple::from_str("aarch64-apple-ios-sim")
+            .unwrap()
+            .is_simulator());
+        assert!(Triple::from_str("aarch64-apple-watchos-sim")
+            .unwrap()
+            .is_simulator());
+        assert!(Triple::from_str("x86_64-apple-ios").unwrap().is_simulator());
+        assert!(!Triple::from_str("aarch64-apple-ios")
+            .unwrap()
+            .is_simulator());
+        assert!(!Triple::from_str("x86_64-apple-ios-macabi")
+            .unwrap()
+            .is_simulator());
+        assert!(!Triple::from_str("x86_64-apple-darwin")
+            .unwrap()
+            .is_simulator());
+    }
+
+    #[test]
+    fn tolerant_parsing() {
+        let extended = Triple::from_str_tolerant("wasm32-unknown-unknown-unknown").unwrap();
+        assert_eq!(
+            extended.triple,
+            Triple::from_str("wasm32-unknown-unknown").unwrap()
+        );
+        assert_eq!(extended.triple.binary_format, BinaryFormat::Wasm);
+        assert_eq!(extended.extra, ["unknown"]);
+        assert_eq!(extended.to_string(), "wasm32-unknown-unknown-unknown");
+
+        let extended = Triple::from_str_tolerant("x86_64-unknown-linux-gnu-foo-bar").unwrap();
+        assert_eq!(
+            extended.triple,
+            Triple::from_str("x86_64-unknown-linux-gnu").unwrap()
+        );
+        assert_eq!(extended.triple.binary_format, BinaryFormat::Elf);
+        assert_eq!(extended.extra, ["foo", "bar"]);
+        assert_eq!(extended.to_string(), "x86_64-unknown-linux-gnu-foo-bar");
+
+        let extended = Triple::from_str_tolerant("arm-unknown-linux-gnueabihf-unknown").unwrap();
+        assert_eq!(
+            extended.triple,
+            Triple::from_str("arm-unknown-linux-gnueabihf").unwrap()
+        );
+        assert_eq!(extended.triple.binary_format, BinaryFormat::Elf);
+        assert_eq!(extended.extra, ["unknown"]);
+
+        let extended = Triple::from_str_tolerant("x86_64-pc-windows-msvc-unknown-foo").unwrap();
+        assert_eq!(extended.triple.environment, Environment::Msvc);
+        assert_eq!(extended.triple.binary_format, BinaryFormat::Coff);
+        assert_eq!(extended.extra, ["unknown", "foo"]);
+
+        let extended = Triple::from_str_tolerant("x86_64-unknown-linux-android-foo").unwrap();
+        assert_eq!(
+            extended.triple,
+            Triple::from_str("x86_64-linux-android").unwrap()
+        );
+        assert_eq!(extended.prefix, "x86_64-unknown-linux-android");
+        assert_eq!(extended.extra, ["foo"]);
+
+        for s in [
+            "arm-unknown-linux-gnueabihf-unknown",
+            "x86_64-pc-windows-msvc-unknown-foo",
+            "x86_64-unknown-linux-android-foo",
+            "x86_64-apple-macosx10.7.0-foo",
+            "x86_64-unknown-linux-gnu",
+        ]
+        .iter()
+        {
+            assert_eq!(Triple::from_str_tolerant(s).unwrap().to_string(), *s);
+        }
+
+        assert_eq!(
+            Triple::from_str_tolerant("foo-unknown-linux"),
+            Err(ParseError::UnrecognizedArchitecture("foo".to_owned()))
+        );
+    }
+
+    #[test]
+    fn expand() {
+        for (short, full) in [
+            ("x86_64-linux", "x86_64-unknown-linux-gnu"),
+            ("armv7-linux", "armv7-unknown-linux-gnueabihf"),
+            ("aarch64-macos", "aarch64-apple-darwin"),
+            ("x86_64-darwin", "x86_64-apple-darwin"),
+            ("aarch64-ios", "aarch64-apple-ios"),
+            ("x86_64-windows", "x86_64-pc-windows-msvc"),
+            ("x86_64-windows-gnu", "x86_64-pc-windows-gnu"),
+            ("aarch64-android", "aarch64-linux-android"),
+            ("armv7-android", "armv7-linux-androideabi"),
+            ("wasm32", "wasm32-unknown-unknown"),
+            ("wasm32-wasi", "wasm32-wasi"),
+            ("x86_64-unknown-freebsd", "x86_64-unknown-freebsd"),
+            ("x86_64-unknown-linux-musl", "x86_64-unknown-linux-musl"),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                Triple::expand(short).unwrap(),
+                Triple::from_str(full).unwrap(),
+                "{}",
+                short
+            );
+        }
+
+        assert_eq!(
+            Triple::expand("foo-linux"),
+            Err(ParseError::UnrecognizedArchitecture("foo".to_owned()))
+        );
+    }
+
+    #[test]
+    fn field_overrides() {
+        let linux = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        assert_eq!(
+            linux.clone().with_environment(Environment::Musl),
+            Triple::from_str("x86_64-unknown-linux-musl").unwrap()
+        );
+        assert_eq!(
+            linux
+                .clone()
+                .with_architecture(Architecture::from_str("aarch64").unwrap()),
+            Triple::from_str("aarch64-unknown-linux-gnu").unwrap()
+        );
+        assert_eq!(
+            linux
+                .clone()
+                .with_vendor(Vendor::Pc)
+                .with_operating_system(OperatingSystem::Windows)
+                .with_environment(Environment::Msvc),
+            Triple::from_str("x86_64-pc-windows-msvc").unwrap()
+        );
+
+        let ios = Triple::from_str("aarch64-apple-ios").unwrap();
+        assert_eq!(
+            ios.with_environment(Environment::Sim),
+            Triple::from_str("aarch64-apple-ios-sim").unwrap()
+        );
+
+        // An explicitly chosen binary format is kept.
+        let bare = Triple::from_str("thumbv7em-none-eabihf-ihex").unwrap();
+        assert_eq!(
+            bare.with_environment(Environment::Eabi),
+            Triple::from_str("thumbv7em-none-eabi-ihex").unwrap()
+        );
+    }
+
+    #[test]
+    fn target_env_and_abi() {
+        let cases = [
+            ("x86_64-unknown-linux-gnu", Environment::Gnu, None),
+            (
+                "armv7-unknown-linux-gnueabihf",
+                Environment::Gnu,
+                Some(Abi::Eabihf),
+            ),
+            (
+                "arm-unknown-linux-musleabi",
+                Environment::Musl,
+                Some(Abi::Eabi),
+            ),
+            (
+                "x86_64-unknown-linux-gnux32",
+                Environment::Gnu,
+                Some(Abi::X32),
+            ),
+            (
+                "thumbv7em-none-eabihf",
+                Environment::Unknown,
+                Some(Abi::Eabihf),
+            ),
+            (
+                "armv7-linux-androideabi",
+                Environment::Unknown,
+                Some(Abi::Eabi),
+            ),
+            (
+                "aarch64-apple-ios-macabi",
+                Environment::Unknown,
+                Some(Abi::MacABI),
+            ),
+            (
+                "aarch64-apple-ios-sim",
+                Environment::Unknown,
+                Some(Abi::Sim),
+            ),
+            ("x86_64-uwp-windows-msvc", Environment::Msvc, Some(Abi::Uwp)),
+            ("x86_64-pc-windows-msvc.vc143.mt", Environment::Msvc, None),
+            (
+                "x86_64-fortanix-unknown-sgx",
+                Environment::Sgx,
+                Some(Abi::Fortanix),
+            ),
+        ];
+        for (s, env, abi) in cases.iter() {
+            let triple = Triple::from_str(s).unwrap();
+            assert_eq!(triple.target_env(), *env, "{}", s);
+            assert_eq!(triple.target_abi(), *abi, "{}", s);
+        }
+    }
+
+    #[test]
+    fn rustc_cfg_values() {
+        let cases = [
+            (
+                "x86_64-unknown-linux-gnu",
+                "x86_64",
+                "unknown",
+                "linux",
+                "gnu",
+            ),
+            ("i686-pc-windows-msvc", "x86", "pc", "windows", "msvc"),
+            ("x86_64-apple-macosx10.7.0", "x86_64", "apple", "macos", ""),
+            ("aarch64-apple-darwin", "aarch64", "apple", "macos", ""),
+            ("thumbv7em-none-eabihf", "arm", "unknown", "none", ""),
+            ("armv7-linux-androideabi", "arm", "unknown", "android", ""),
+            (
+                "arm-unknown-linux-musleabihf",
+                "arm",
+                "unknown",
+                "linux",
+                "musl",
+            ),
+            (
+                "powerpc64le-unknown-linux-gnu",
+                "powerpc64",
+                "unknown",
+                "linux",
+                "gnu",
+            ),
+            ("sparcv9-sun-solaris", "sparc64", "sun", "solaris", ""),
+            (
+                "mipsisa32r6el-unknown-linux-gnu",
+                "mips32r6",
+                "unknown",
+                "linux",
+                "gnu",
+            ),
+            (
+                "x86_64-customvendor-linux",
+                "x86_64",
+                "customvendor",
+                "linux",
+                "",
+            ),
+            (
+                "xtensa-esp32-espidf",
+                "xtensa",
+                "espressif",
+                "espidf",
+                "newlib",
+            ),
+            (
+                "riscv32imc-esp-espidf",
+                "riscv32",
+                "espressif",
+                "espidf",
+                "newlib",
+            ),
+        ];
+        for (s, arch, vendor, os, env) in cases.iter() {
+            let triple = Triple::from_str(s).unwrap();
+            assert_eq!(triple.target_arch_str(), *arch, "{}", s);
+            assert_eq!(triple.target_vendor_str(), *vendor, "{}", s);
+            assert_eq!(triple.target_os_str(), *os, "{}", s);
+            assert_eq!(triple.target_env_str(), *env, "{}", s);
+        }
+    }
+
+    #[test]
+    fn const_accessors() {
+        const LINUX: Triple = Triple {
+            architecture: Architecture::X86_64,
+            vendor: Vendor::Unknown,
+            operating_system: OperatingSystem::Linux,
+            environment: Environment::Gnu,
+            binary_format: BinaryFormat::Elf,
+        };
+        const POINTER_WIDTH: Result<PointerWidth, ()> = LINUX.pointer_width();
+        const ENDIANNESS: Result<Endianness, ()> = LINUX.endianness();
+        const CALLING_CONVENTION: Result<CallingConvention, ()> =
+            LINUX.default_calling_convention();
+        const DATA_MODEL: Result<CDataModel, ()> = LINUX.data_model();
+        const LONG_BITS: u8 = match DATA_MODEL {
+            Ok(data_model) => data_model.long_size().bits(),
+            Err(()) => 0,
+        };
+
+        assert_eq!(POINTER_WIDTH, Ok(PointerWidth::U64));
+        assert_eq!(ENDIANNESS, Ok(Endianness::Little));
+        assert_eq!(CALLING_CONVENTION, Ok(CallingConvention::SystemV));
+        assert_eq!(DATA_MODEL, Ok(CDataModel::LP64));
+        assert_eq!(LONG_BITS, 64);
+    }
+
+    #[test]
+    fn const_parsing() {
+        const LINUX: Triple = triple!("x86_64-unknown-linux-gnu");
+        const MACOS: Option<Triple> = Triple::from_str_const("x86_64-apple-macosx10.7.0");
+        const CUSTOM: Triple = triple!("riscv32imac-acme-none-elf");
+        const TYPO: Option<Triple> = Triple::from_str_const("x86_64-unknown-linus-gnu");
+
+        assert_eq!(LINUX, Triple::from_str("x86_64-unknown-linux-gnu").unwrap());
+        assert_eq!(MACOS, Triple::from_str("x86_64-apple-macosx10.7.0").ok());
+        assert_eq!(
+            CUSTOM.vendor,
+            Vendor::Custom(crate::CustomVendor::Static("acme"))
+        );
+        assert_eq!(
+            CUSTOM,
+            Triple::from_str("riscv32imac-acme-none-elf").unwrap()
+        );
+        assert_eq!(TYPO, None);
+
+        for vector in crate::test_vectors::TEST_VECTORS {
+            assert_eq!(
+                Triple::from_str_const(vector.triple),
+                Triple::from_str(vector.triple).ok(),
+                "{}",
+                vector.triple
+            );
+        }
+        for s in [
+            "",
+            "x86_64-",
+            "x86_64-apple-macosx10.7",
+            "x86_64-pc-windows-msvc.vc143.nope",
+        ]
+        .iter()
+        {
+            assert_eq!(Triple::from_str_const(s), None, "{}", s);
+            assert!(Triple::from_str(s).is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn triple_macro_runtime() {
+        let owned = String::from("x86_64-unknown-linux-gnu");
+        let name: &str = &owned;
+        assert_eq!(triple!(name), triple!("x86_64-unknown-linux-gnu"));
+        assert_eq!(
+            triple!(&owned),
+            Triple::from_str("x86_64-unknown-linux-gnu").unwrap()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid triple literal")]
+    fn triple_macro_runtime_invalid() {
+        let name = String::from("x86_64-unknown-linus-gnu");
+        triple!(&name);
+    }
+
+    #[test]
+    fn slugs() {
+        let cases = [
+            ("x86_64-unknown-linux-gnu", "x86_64__unknown__linux__gnu"),
+            ("x86_64-apple-macosx10.7.0", "x86_64__apple__macosx10_p7_p0"),
+            (
+                "x86_64-pc-windows-msvc.vc143.mt",
+                "x86_64__pc__windows__msvc_pvc143_pmt",
+            ),
+            (
+                "aarch64-unknown-linux-gnu_ilp32",
+                "aarch64__unknown__linux__gnu_ilp32",
+            ),
+            ("x86_64-foo_p_-linux", "x86_64__foo_up_u__linux"),
+        ];
+        for (s, slug) in cases.iter() {
+            let triple = Triple::from_str(s).unwrap();
+            assert_eq!(triple.to_slug(), *slug);
+            assert!(slug.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+            assert_eq!(Triple::from_slug(slug), Ok(triple));
+        }
+
+        for vector in crate::test_vectors::TEST_VECTORS {
+            let triple = Triple::from_str(vector.triple).unwrap();
+            assert_eq!(Triple::from_slug(&triple.to_slug()), Ok(triple));
+        }
+    }
+
+    #[test]
+    fn lldb_triples() {
+        let pairs = [
+            ("aarch64-apple-darwin", "arm64-apple-macosx"),
+            ("x86_64-apple-macosx10.7.0", "x86_64-apple-macosx10.7.0"),
+            ("aarch64-apple-ios", "arm64-apple-ios"),
+            ("thumbv7em-none-eabihf", "thumbv7em-unknown-none-eabihf"),
+            ("x86_64-unknown-linux-gnu", "x86_64-unknown-linux-gnu"),
+            ("wasm32-wasi", "wasm32-unknown-wasi"),
+        ];
+        for (triple, lldb) in pairs.iter() {
+            assert_eq!(Triple::from_str(triple).unwrap().lldb_triple(), *lldb);
+        }
+    }
+
+    #[test]
+    fn rustup_targets() {
+        let pairs = [
+            ("x86_64-unknown-linux-gnu", "x86_64-unknown-linux-gnu"),
+            ("wasm32-wasi", "wasm32-wasip1"),
+            ("x86_64-apple-macosx10.7.0", "x86_64-apple-darwin"),
+            ("arm64-apple-darwin", "aarch64-apple-darwin"),
+            ("x86_64-pc-windows-msvc.vc143.mt", "x86_64-pc-windows-msvc"),
+            ("x86_64-pc-windows-gnu.ucrt", "x86_64-pc-windows-gnu"),
+            ("x86_64-sun-solaris", "x86_64-pc-solaris"),
+            ("sparcv9-sun-solaris", "sparcv9-sun-solaris"),
+            ("thumbv7em-none-eabihf", "thumbv7em-none-eabihf"),
+            ("armv4t-unknown-none-eabi", "armv4t-none-eabi"),
+            ("hexagon-unknown-none-elf", "hexagon-unknown-none-elf"),
+            ("x86_64-unknown-none", "x86_64-unknown-none"),
+            ("x86_64-fuchsia", "x86_64-unknown-fuchsia"),
+            ("thumbv7em-unknown-nuttx-eabi", "thumbv7em-nuttx-eabi"),
+            ("riscv32imc-unknown-nuttx", "riscv32imc-unknown-nuttx-elf"),
+            ("armv7-unknown-rtems-eabihf", "armv7-rtems-eabihf"),
+            ("riscv32imc-espressif-espidf", "riscv32imc-esp-espidf"),
+            ("xtensa-esp32s2-none-elf", "xtensa-esp32s2-none-elf"),
+            ("x86_64-sony-ps4", "x86_64-scei-ps4"),
+            ("x86_64-sony-ps5", "x86_64-sie-ps5"),
+        ];
+        for (triple, name) in pairs.iter() {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.to_rustup_target(), *name);
+            assert_eq!(
+                Triple::from_rustup_target(name).unwrap().to_rustup_target(),
+                *name
+            );
+        }
+        for (renamed, triple) in RUSTUP_RENAMES.iter() {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.to_rustup_target(), *renamed);
+            assert_eq!(Triple::from_rustup_target(renamed), Ok(triple.clone()));
+            assert_eq!(
+                Triple::from_rustup_target(&triple.to_rustup_target()),
+                Ok(triple)
+            );
+        }
+        for (alias, name) in [
+            ("wasm32-wasi", "wasm32-wasip1"),
+            ("x86_64-sun-solaris", "x86_64-pc-solaris"),
+            ("arm64-apple-darwin", "aarch64-apple-darwin"),
+            ("arm64-apple-ios", "aarch64-apple-ios"),
+        ]
+        .iter()
+        {
+            assert_eq!(
+                Triple::from_rustup_target(alias),
+                Triple::from_rustup_target(name),
+                "{}",
+                alias
+            );
+        }
+        assert_eq!(
+            Triple::from_rustup_target("wasm32-wasip1-threads"),
+            Err(ParseError::UnrecognizedEnvironment("threads".to_owned()))
+        );
+    }
+
+    #[test]
+    fn threading() {
+        let cases = [
+            ("x86_64-unknown-linux-gnu", Threading::Threads),
+            ("aarch64-apple-ios", Threading::Threads),
+            ("x86_64-fortanix-unknown-sgx", Threading::Threads),
+            ("xtensa-esp32-espidf", Threading::Threads),
+            ("wasm32-unknown-unknown", Threading::AtomicsOnly),
+            ("wasm32-wasi", Threading::AtomicsOnly),
+            ("thumbv7em-none-eabihf", Threading::AtomicsOnly),
+            ("riscv32imac-unknown-none-elf", Threading::AtomicsOnly),
+            ("thumbv6m-none-eabi", Threading::Unsupported),
+            ("riscv32imc-unknown-none-elf", Threading::Unsupported),
+        ];
+        for (triple, threading) in cases.iter() {
+            assert_eq!(
+                Triple::from_str(triple).unwrap().threading(),
+                Ok(*threading),
+                "{}",
+                triple
+            );
+        }
+        assert_eq!(Triple::unknown().threading(), Err(()));
+    }
+
+    #[test]
+    fn crate_types() {
+        let linux = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        let musl = Triple::from_str("x86_64-unknown-linux-musl").unwrap();
+        let wasm = Triple::from_str("wasm32-wasi").unwrap();
+        let bare = Triple::from_str("thumbv7em-none-eabihf").unwrap();
+        let rtos = [
+            "xtensa-esp32-espidf",
+            "thumbv7em-nuttx-eabi",
+            "armv7-rtems-eabihf",
+            "mipsel-sony-psp",
+            "mipsel-sony-psx",
+            "armv7-sony-vita-newlibeabihf",
+            "armv7a-kmc-solid_asp3-eabi",
+            "aarch64-nintendo-switch-freestanding",
+        ];
+
+        for crate_type in [
+            CrateType::Bin,
+            CrateType::Rlib,
+            CrateType::Dylib,
+            CrateType::Cdylib,
+            CrateType::Staticlib,
+            CrateType::ProcMacro,
+        ]
+        .iter()
+        {
+            assert_eq!(linux.supports_crate_type(*crate_type), Ok(()));
+        }
+        for triple in [&musl, &wasm, &bare].iter() {
+            assert_eq!(triple.supports_crate_type(CrateType::Staticlib), Ok(()));
+            assert!(triple.supports_crate_type(CrateType::Dylib).is_err());
+            assert!(triple.supports_crate_type(CrateType::ProcMacro).is_err());
+        }
+        assert_eq!(wasm.supports_crate_type(CrateType::Cdylib), Ok(()));
+        assert!(musl.supports_crate_type(CrateType::Cdylib).is_err());
+        assert!(bare.supports_crate_type(CrateType::Cdylib).is_err());
+        for triple in rtos.iter() {
+            let triple = Triple::from_str(triple).unwrap();
+            assert_eq!(triple.supports_crate_type(CrateType::Staticlib), Ok(()));
+            assert!(triple.supports_crate_type(CrateType::Cdylib).is_err());
+            assert!(triple.supports_crate_type(CrateType::Dylib).is_err());
+        }
+    }
+
+    #[test]
+    fn diff() {
+        let gnu = Triple::from_str("x86_64-unknown-linux-gnu").unwrap();
+        let musl = Triple::from_str("x86_64-unknown-linux-musl").unwrap();
+        let windows = Triple::from_str("aarch64-pc-windows-msvc").unwrap();
+
+        let diff = gnu.diff(&gnu);
+        assert!(diff.is_empty());
+        assert!(diff.components().is_empty());
+        assert_eq!(diff.to_string(), "the triples are the same");
+
+        let diff = gnu.diff(&musl);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.components(), [TripleComponent::Environment]);
+        assert_eq!(diff.to_string(), "environment differs (gnu vs musl)");
+
+        assert_eq!(
+            gnu.diff(&windows).components(),
+            [
+                TripleComponent::Architecture,
+                TripleComponent::Vendor,
+                TripleComponent::OperatingSystem,
+                TripleComponent::Environment,
+                TripleComponent::BinaryFormat,
+            ]
+        );
+        assert_eq!(
+            gnu.diff(&windows).to_string(),
+            "architecture differs (x86_64 vs aarch64), vendor differs (unknown vs pc), \
+             operating system differs (linux vs windows), environment differs (gnu vs msvc), \
+             binary format differs (elf vs coff)"
+        );
+    }
 }
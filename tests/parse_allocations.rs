@@ -0,0 +1,62 @@
+//! Check that parsing triples with only recognized components doesn't
+//! allocate. This needs its own global allocator, so it lives in its own
+//! test binary.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::str::FromStr;
+use target_lexicon::Triple;
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations_during<F: FnOnce()>(f: F) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn known_triples_parse_without_allocating() {
+    let targets = [
+        "aarch64-apple-ios-sim",
+        "aarch64-linux-android",
+        "aarch64-unknown-linux-gnu_ilp32",
+        "amdgcn-amd-amdhsa-amdgiz",
+        "armv7a-none-eabihf",
+        "i686-apple-macosx10.7.0",
+        "m68k-elf",
+        "riscv32imac-unknown-none-elf",
+        "thumbv7em-none-eabihf-ihex",
+        "wasm32-wasi",
+        "x86_64-fortanix-unknown-sgx",
+        "x86_64-linux-kernel",
+        "x86_64-pc-windows-gnu.ucrt",
+        "x86_64-pc-windows-msvc.vc143.mt",
+        "x86_64-unknown-linux-gnu",
+    ];
+
+    for target in targets.iter() {
+        let mut triple = None;
+        let count = allocations_during(|| triple = Some(Triple::from_str(target)));
+        assert!(triple.unwrap().is_ok(), "can't parse {}", target);
+        assert_eq!(count, 0, "parsing {} allocated", target);
+    }
+}